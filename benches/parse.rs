@@ -0,0 +1,20 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use feedback::parse::parse;
+
+fn parse_frames(c: &mut Criterion) {
+    let frames: [(&str, &[u8]); 4] = [
+        ("wheels", &[0x01, 0x01, 126, 126, 126, 126, 126, 126, 244]),
+        ("arm", &[0x02, 1, 2, 3, 4, 5, 6, 21]),
+        ("led", &[0x01, 0x02, 255, 0, 0]),
+        ("ack", &[0x05, 17, 0x00]),
+    ];
+
+    let mut group = c.benchmark_group("parse");
+    for (name, frame) in frames {
+        group.bench_function(name, |b| b.iter(|| parse(black_box(frame))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse_frames);
+criterion_main!(benches);
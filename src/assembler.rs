@@ -0,0 +1,138 @@
+//! # Assembler
+//!
+//! Rebuilds whole frames from a byte stream (like TCP) that can split them
+//! across reads.
+
+use crate::{
+    error::ParsingError,
+    parse::{self, expected_len_for, Message},
+};
+
+/// Buffers bytes from a stream and yields each message once all of its bytes
+/// have arrived.
+///
+/// Frame boundaries come from each subsystem's expected length, and every
+/// frame is checked with `Message::validate` before it's yielded. Custom
+/// subsystems from a [`ParserRegistry`] aren't supported, since their lengths
+/// aren't known.
+///
+/// If a frame is badly framed (an unknown subsystem or part, or a bad
+/// checksum), the assembler assumes it lost track of where frames start. It
+/// yields the error, then resyncs: it skips ahead to the next byte that starts
+/// a plausible frame, meaning one with a known subsystem, the right length,
+/// and a valid checksum. Garbage can still look like a frame by chance,
+/// especially an LED or ack frame, which have no checksum.
+///
+/// A frame that's framed correctly but fails the rest of `Message::validate`,
+/// like a science frame with an out-of-range field, is dropped whole. Its
+/// error is yielded, and the next frame is read from right after it.
+///
+/// [`ParserRegistry`]: crate::registry::ParserRegistry
+///
+/// ```
+/// # use feedback::{assembler::FrameAssembler, error::ParsingError, parse::Message};
+/// #
+/// let mut assembler = FrameAssembler::new();
+///
+/// // an arm frame and an LED frame, split across three reads
+/// assembler.push(&[0x02, 1, 2]);
+/// assert!(assembler.next().is_none());
+///
+/// assembler.push(&[3, 4, 5, 6, 21, 0x01]);
+/// assert!(matches!(assembler.next(), Some(Ok(Message::Arm(_)))));
+/// assert!(assembler.next().is_none());
+///
+/// assembler.push(&[0x02, 255, 0, 0, 0x09]);
+/// assert!(matches!(assembler.next(), Some(Ok(Message::Led(_)))));
+///
+/// // `0x09` isn't a subsystem, so it's reported and skipped
+/// assert!(matches!(assembler.next(), Some(Err(ParsingError::InvalidSubsystem(0x09)))));
+/// assert!(assembler.next().is_none());
+///
+/// // garbage before a frame is skipped in one go, including the `0x03` that
+/// // looks like a science frame until its checksum doesn't match
+/// assembler.push(&[0xff, 0x03, 9, 9, 9, 9, 9, 9, 0x02, 1, 2, 3, 4, 5, 6, 21]);
+/// assert!(matches!(assembler.next(), Some(Err(ParsingError::InvalidSubsystem(0xff)))));
+/// assert!(matches!(assembler.next(), Some(Ok(Message::Arm(_)))));
+/// assert_eq!(assembler.buffered(), 0);
+///
+/// // a well-framed science frame for a test tube that doesn't exist is
+/// // dropped whole, without reading frames out of its payload
+/// assembler.push(&[0x03, 0x05, 0, 0, 12, 0, 17, 0x02, 1, 2, 3, 4, 5, 6, 21]);
+/// assert!(matches!(assembler.next(), Some(Err(ParsingError::OutOfRange { field: "test_tubes", .. }))));
+/// assert!(matches!(assembler.next(), Some(Ok(Message::Arm(_)))));
+/// assert!(assembler.next().is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FrameAssembler {
+    buffer: Vec<u8>,
+}
+
+impl FrameAssembler {
+    /// Creates an assembler with nothing buffered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds bytes read from the stream.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// How many bytes are buffered that haven't made up a whole frame yet.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl Iterator for FrameAssembler {
+    type Item = Result<Message, ParsingError>;
+
+    /// Returns the next whole message, or `None` if more bytes are needed.
+    fn next(&mut self) -> Option<Self::Item> {
+        match check_frame(&self.buffer)? {
+            Ok(message) => {
+                self.buffer.drain(..message.encoded_len());
+                Some(message.validate().map(|()| message))
+            }
+            Err(e) => {
+                self.resync();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl FrameAssembler {
+    /// Drops bytes from the front of the buffer until it starts with something
+    /// that could be a valid frame. The first byte is always dropped.
+    fn resync(&mut self) {
+        let start = (1..self.buffer.len())
+            .find(|&i| !matches!(check_frame(&self.buffer[i..]), Some(Err(_))))
+            .unwrap_or(self.buffer.len());
+
+        self.buffer.drain(..start);
+    }
+}
+
+/// Checks if `bytes` starts with a whole, correctly framed frame: a known
+/// subsystem and part, and a matching checksum. Returns its message if so,
+/// without checking the rest of `Message::validate`, or `None` if more bytes
+/// are needed to tell.
+fn check_frame(bytes: &[u8]) -> Option<Result<Message, ParsingError>> {
+    let &subsystem = bytes.first()?;
+
+    if !parse::is_known_subsystem(subsystem) {
+        return Some(Err(ParsingError::InvalidSubsystem(subsystem)));
+    }
+
+    let part = bytes.get(1).copied();
+    let Some(length) = expected_len_for(subsystem, part) else {
+        // this subsystem has parts, and we either don't have one yet or it's
+        // not a real one
+        return Some(Err(ParsingError::InvalidSubsystem(part?)));
+    };
+
+    let frame = bytes.get(..length as usize)?;
+    Some(parse::verify_frame(frame).and_then(|()| parse::parse(frame)))
+}
@@ -1,4 +1,4 @@
-use crate::{Arm, Science, Wheels};
+use crate::{Arm, Science, ScienceTelemetry, Wheels};
 
 pub trait Checksum<const T: usize> {
     /// Creates an array of the bytes that'll be checksummed.
@@ -67,3 +67,13 @@ impl Checksum<5> for Science {
         self.checksum == self.checksum()
     }
 }
+
+impl Checksum<2> for ScienceTelemetry {
+    fn to_checksum_array(&self) -> [u8; 2] {
+        [self.big_actuator, self.small_actuator]
+    }
+
+    fn is_checksum_correct(&self) -> bool {
+        self.checksum == self.checksum()
+    }
+}
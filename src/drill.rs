@@ -0,0 +1,88 @@
+//! # Drill
+//!
+//! Estimates how deep the science package's drill is, from the commands sent
+//! to it.
+
+use std::time::Duration;
+
+use crate::{signed_speed, Science, Wheels};
+
+/// Estimates drill depth by adding up how far the actuators were commanded to
+/// move.
+///
+/// The actuator bytes are speeds, as described on `Science`, with speed
+/// proportional to the distance from `Wheels::NEURTAL_SPEED`. There's a little more room
+/// above neutral than below, so everything past `252` counts as full speed.
+/// Depth is in millimeters, and never goes above the surface (zero).
+///
+/// This is dead reckoning from commands, not a measurement, so it drifts if
+/// the actuators stall or slip. Reset it whenever the drill is known to be at
+/// the surface.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use feedback::{drill::DrillController, Science};
+/// #
+/// // the big actuator moves 10 mm/s at full speed, the small one 2 mm/s
+/// let mut drill = DrillController::new(10.0, 2.0);
+///
+/// let extend = Science::new(252, 0, 126, 0, 0, 122).unwrap();
+/// let retract = Science::new(0, 0, 126, 0, 0, 126).unwrap();
+///
+/// drill.update(&extend, Duration::from_secs(3));
+/// assert!((drill.depth_estimate() - 30.0).abs() < 1e-9);
+///
+/// drill.update(&retract, Duration::from_secs(1));
+/// assert!((drill.depth_estimate() - 20.0).abs() < 1e-9);
+///
+/// // anything past full speed is still full speed
+/// let mut fast = DrillController::new(10.0, 2.0);
+/// fast.update(&Science::new(255, 0, 126, 0, 0, 125).unwrap(), Duration::from_secs(1));
+/// assert!((fast.depth_estimate() - 10.0).abs() < 1e-9);
+///
+/// // it can't go above the surface
+/// drill.update(&retract, Duration::from_secs(10));
+/// assert_eq!(drill.depth_estimate(), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrillController {
+    big_actuator_rate: f64,
+    small_actuator_rate: f64,
+    depth: f64,
+}
+
+impl DrillController {
+    /// Creates a new estimator with the drill at the surface.
+    ///
+    /// The rates are how fast each actuator moves the drill at full speed, in
+    /// millimeters per second.
+    pub fn new(big_actuator_rate: f64, small_actuator_rate: f64) -> Self {
+        Self {
+            big_actuator_rate,
+            small_actuator_rate,
+            depth: 0.0,
+        }
+    }
+
+    /// Adds the movement from holding the given command for `elapsed`.
+    pub fn update(&mut self, command: &Science, elapsed: Duration) {
+        let speed = |actuator: u8| {
+            (signed_speed(actuator) as f64 / Wheels::NEURTAL_SPEED as f64).clamp(-1.0, 1.0)
+        };
+
+        let rate = speed(command.big_actuator) * self.big_actuator_rate
+            + speed(command.small_actuator) * self.small_actuator_rate;
+
+        self.depth = (self.depth + rate * elapsed.as_secs_f64()).max(0.0);
+    }
+
+    /// The estimated depth of the drill, in millimeters below the surface.
+    pub fn depth_estimate(&self) -> f64 {
+        self.depth
+    }
+
+    /// Sets the estimate back to the surface.
+    pub fn reset(&mut self) {
+        self.depth = 0.0;
+    }
+}
@@ -4,7 +4,48 @@ use thiserror::Error;
 ///
 /// Doesn't appear in Python - they're cast to strings and raised
 /// as `ValueError`s.
-#[derive(Clone, Copy, Debug, Error, PartialEq, PartialOrd)]
+///
+/// Errors can be compared for equality, but not ordered - no error is
+/// "less than" another.
+///
+/// ```compile_fail
+/// # use feedback::error::ParsingError;
+/// #
+/// let _ = ParsingError::ZeroLengthSlice < ParsingError::MalformedMessage;
+/// ```
+///
+/// With the `serde` feature, errors serialize with their variant and field
+/// names, so dashboards can show them as JSON:
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// # use feedback::error::ParsingError;
+/// # use serde_json::json;
+/// #
+/// let json = |e: ParsingError| serde_json::to_value(e).unwrap();
+///
+/// assert_eq!(json(ParsingError::ZeroLengthSlice), json!("ZeroLengthSlice"));
+/// assert_eq!(json(ParsingError::InvalidSubsystem(9)), json!({ "InvalidSubsystem": 9 }));
+/// assert_eq!(json(ParsingError::NoEboxPart), json!("NoEboxPart"));
+/// assert_eq!(
+///     json(ParsingError::LengthInconsistency { subsystem: 2, part: 0, length: 3, expected_length: 8 }),
+///     json!({ "LengthInconsistency": { "subsystem": 2, "part": 0, "length": 3, "expected_length": 8 } })
+/// );
+/// assert_eq!(json(ParsingError::MalformedMessage), json!("MalformedMessage"));
+/// assert_eq!(json(ParsingError::InvalidColor), json!("InvalidColor"));
+/// assert_eq!(
+///     json(ParsingError::OutOfRange { field: "test_tubes", value: 12, max: 11 }),
+///     json!({ "OutOfRange": { "field": "test_tubes", "value": 12, "max": 11 } })
+/// );
+/// assert_eq!(
+///     json(ParsingError::BadChecksum { subsystem: 2, expected: 21, found: 0 }),
+///     json!({ "BadChecksum": { "subsystem": 2, "expected": 21, "found": 0 } })
+/// );
+/// assert_eq!(json(ParsingError::LedOff), json!("LedOff"));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Error, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ParsingError {
     #[error("You must supply a slice with a length greater than zero.")]
     ZeroLengthSlice,
@@ -27,4 +68,98 @@ pub enum ParsingError {
     },
     #[error("The given slice was malformed.")]
     MalformedMessage,
+    #[error("The given string isn't a color name, `#RRGGBB`, or `rgb(r, g, b)`.")]
+    InvalidColor,
+    #[error("The `{field}` field was `{value}`, but it can be at most `{max}`.")]
+    OutOfRange {
+        field: &'static str,
+        value: u8,
+        max: u8,
+    },
+    #[error("The checksum for subsystem `{subsystem:x}` was `{found}`, but the payload sums to `{expected}`.")]
+    BadChecksum {
+        subsystem: u8,
+        expected: u8,
+        found: u8,
+    },
+    #[error("The LED was set to all zeros, which turns it off.")]
+    LedOff,
+}
+
+impl ParsingError {
+    /// A plain-language hint on how to fix the frame that caused this error,
+    /// suitable for showing to an operator.
+    ///
+    /// ```
+    /// # use feedback::error::ParsingError;
+    /// #
+    /// assert_eq!(
+    ///     ParsingError::ZeroLengthSlice.remediation(),
+    ///     "Send at least one byte: the first byte picks the subsystem."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::InvalidSubsystem(0x09).remediation(),
+    ///     "Check the first byte (and the second, for wheels or LEDs) against the subsystems this version supports."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::NoEboxPart.remediation(),
+    ///     "Send at least 2 bytes, where byte 1 is 0x01 for wheels or 0x02 for LEDs."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::LengthInconsistency { subsystem: 0x02, part: 0x00, length: 3, expected_length: 8 }
+    ///         .remediation(),
+    ///     "Resend the frame with the length the error lists; bytes were likely dropped or added in transit."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::MalformedMessage.remediation(),
+    ///     "The frame's layout doesn't match the protocol. Make sure both ends run the same protocol version."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::InvalidColor.remediation(),
+    ///     "Use a color name like `red`, a hex code like `#FF0000`, or `rgb(255, 0, 0)`."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::OutOfRange { field: "test_tubes", value: 12, max: 11 }.remediation(),
+    ///     "Bring the field the error lists back within its allowed range."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::BadChecksum { subsystem: 0x02, expected: 21, found: 0 }.remediation(),
+    ///     "Recompute the checksum after changing any field, or check the link for corruption."
+    /// );
+    /// assert_eq!(
+    ///     ParsingError::LedOff.remediation(),
+    ///     "Set a color, or allow an all-zero LED if turning it off was intended."
+    /// );
+    /// ```
+    pub const fn remediation(&self) -> &'static str {
+        match self {
+            ParsingError::ZeroLengthSlice => {
+                "Send at least one byte: the first byte picks the subsystem."
+            }
+            ParsingError::InvalidSubsystem(_) => {
+                "Check the first byte (and the second, for wheels or LEDs) against the subsystems this version supports."
+            }
+            ParsingError::NoEboxPart => {
+                "Send at least 2 bytes, where byte 1 is 0x01 for wheels or 0x02 for LEDs."
+            }
+            ParsingError::LengthInconsistency { .. } => {
+                "Resend the frame with the length the error lists; bytes were likely dropped or added in transit."
+            }
+            ParsingError::MalformedMessage => {
+                "The frame's layout doesn't match the protocol. Make sure both ends run the same protocol version."
+            }
+            ParsingError::InvalidColor => {
+                "Use a color name like `red`, a hex code like `#FF0000`, or `rgb(255, 0, 0)`."
+            }
+            ParsingError::OutOfRange { .. } => {
+                "Bring the field the error lists back within its allowed range."
+            }
+            ParsingError::BadChecksum { .. } => {
+                "Recompute the checksum after changing any field, or check the link for corruption."
+            }
+            ParsingError::LedOff => {
+                "Set a color, or allow an all-zero LED if turning it off was intended."
+            }
+        }
+    }
 }
@@ -14,16 +14,29 @@
 //!
 //!
 
+use std::str::FromStr;
+
 use pyo3::prelude::*;
 
+use crate::{checksum::Checksum, error::ParsingError};
+
+pub mod assembler;
 pub mod checksum;
+pub mod drill;
 pub mod error;
 pub mod parse;
+pub mod registry;
+#[cfg(feature = "ros")]
+pub mod ros;
+pub mod schema;
 
 /// For the Rover, the Wheels struct represents the current state of each of the six wheels.
 /// Each `wheelx` value is a u8, with the neutral position being 126.
+///
+/// `wheel0` through `wheel2` are on the left side of the Rover, and `wheel3`
+/// through `wheel5` are on the right.
 #[pyclass]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Wheels {
     pub wheel0: u8,
     pub wheel1: u8,
@@ -39,6 +52,8 @@ pub struct Wheels {
 impl Wheels {
     pub const SUBSYSTEM_BYTE: u8 = 0x01;
     pub const PART_BYTE: u8 = 0x01;
+    /// The length of a wheels frame, including the subsystem and part bytes.
+    pub const FRAME_LENGTH: u32 = 9;
 
     /// The motor value at which a motor isn't moving.
     pub const NEURTAL_SPEED: u8 = 126;
@@ -65,14 +80,230 @@ impl Wheels {
         }
     }
 
+    /// Creates a new `Wheels` object from signed speeds for each side, where
+    /// `0` is neutral. Speeds are clamped to `-126..=129`, and the checksum is
+    /// computed for you.
+    ///
+    /// ```
+    /// # use feedback::Wheels;
+    /// #
+    /// let stopped = Wheels::from_signed(0, 0);
+    /// assert_eq!(stopped.wheel0, Wheels::NEURTAL_SPEED);
+    /// assert_eq!((stopped.left_signed(), stopped.right_signed()), (0, 0));
+    ///
+    /// let spin = Wheels::from_signed(-1000, 1000);
+    /// assert_eq!((spin.wheel0, spin.wheel5), (0, 255));
+    /// assert_eq!((spin.left_signed(), spin.right_signed()), (-126, 129));
+    /// ```
+    #[staticmethod]
+    pub fn from_signed(left: i16, right: i16) -> Self {
+        let (left, right) = (unsigned_speed(left), unsigned_speed(right));
+        let mut wheels = Self::new(left, left, left, right, right, right, 0);
+        wheels.checksum = wheels.checksum();
+        wheels
+    }
+
+    /// Creates a new `Wheels` object that spins the Rover in place. `rate`
+    /// goes from `-1.0` (full speed counterclockwise) to `1.0` (full speed
+    /// clockwise), and is clamped to that range.
+    ///
+    /// Both sides move at the same speed in opposite directions. Full speed is
+    /// `126` away from neutral, since that's as far as both directions can go.
+    ///
+    /// ```
+    /// # use feedback::Wheels;
+    /// #
+    /// assert!(Wheels::turn_in_place(0.0).is_stopped());
+    ///
+    /// let clockwise = Wheels::turn_in_place(1.0);
+    /// assert_eq!((clockwise.left_signed(), clockwise.right_signed()), (126, -126));
+    ///
+    /// let counterclockwise = Wheels::turn_in_place(-1.0);
+    /// assert_eq!((counterclockwise.wheel0, counterclockwise.wheel5), (0, 252));
+    /// ```
+    #[staticmethod]
+    pub fn turn_in_place(rate: f32) -> Self {
+        let speed = (rate.clamp(-1.0, 1.0) * Self::NEURTAL_SPEED as f32).round() as i16;
+        Self::from_signed(speed, -speed)
+    }
+
+    /// The left side's speed as a signed offset from neutral.
+    ///
+    /// If the left wheels disagree, this is their average, rounded toward zero.
+    pub fn left_signed(&self) -> i16 {
+        (signed_speed(self.wheel0) + signed_speed(self.wheel1) + signed_speed(self.wheel2)) / 3
+    }
+
+    /// The right side's speed as a signed offset from neutral.
+    ///
+    /// If the right wheels disagree, this is their average, rounded toward zero.
+    pub fn right_signed(&self) -> i16 {
+        (signed_speed(self.wheel3) + signed_speed(self.wheel4) + signed_speed(self.wheel5)) / 3
+    }
+
+    /// Creates a new `Wheels` object that drives along an arc, using a
+    /// skid-steer model.
+    ///
+    /// - `v` is the speed of the rover's center, from `-1.0` (full reverse) to
+    ///   `1.0` (full forward).
+    /// - `kappa` is the curvature, `1 / turning radius`. Positive turns left
+    ///   (counterclockwise), negative turns right, and `0.0` is straight.
+    /// - `track_width` is the distance between the left and right wheels, in
+    ///   the same unit as the turning radius.
+    ///
+    /// Each side runs at `v * (1 ∓ kappa * track_width / 2)`. If that puts
+    /// either side past full speed, both sides are scaled down together, so
+    /// the rover still follows the same arc, just slower.
+    ///
+    /// Like `Wheels::turn_in_place`, full speed is `126` away from neutral in
+    /// both directions. Forward has a few more steps, but using them would
+    /// make the sides uneven whenever they turn in opposite directions, and
+    /// bend the arc.
+    ///
+    /// An infinite `kappa` turns in place, with `v.abs()` as the rate passed
+    /// to `Wheels::turn_in_place`.
+    ///
+    /// ```
+    /// # use feedback::Wheels;
+    /// #
+    /// // straight ahead at half speed
+    /// let straight = Wheels::from_velocity_curvature(0.5, 0.0, 1.0);
+    /// assert_eq!((straight.left_signed(), straight.right_signed()), (63, 63));
+    ///
+    /// // spinning counterclockwise in place, evenly
+    /// let spin = Wheels::from_velocity_curvature(1.0, f32::INFINITY, 1.0);
+    /// assert_eq!(spin, Wheels::turn_in_place(-1.0));
+    /// assert_eq!((spin.left_signed(), spin.right_signed()), (-126, 126));
+    ///
+    /// // the outside wheel would need 150%, so both sides slow down
+    /// let arc = Wheels::from_velocity_curvature(1.0, 1.0, 1.0);
+    /// assert_eq!((arc.left_signed(), arc.right_signed()), (42, 126));
+    /// ```
+    #[staticmethod]
+    pub fn from_velocity_curvature(v: f32, kappa: f32, track_width: f32) -> Self {
+        if kappa.is_infinite() {
+            return Self::turn_in_place(-v.abs() * kappa.signum());
+        }
+
+        let turn = kappa * track_width / 2.0;
+        let (left, right) = (v * (1.0 - turn), v * (1.0 + turn));
+
+        let fastest = left.abs().max(right.abs()).max(1.0);
+        let speed = |side: f32| (side / fastest * Self::NEURTAL_SPEED as f32).round() as i16;
+        Self::from_signed(speed(left), speed(right))
+    }
+
+    /// Creates a new `Wheels` object from each side's speed as a percentage of
+    /// full speed, from `-100.0` (full reverse) to `100.0` (full forward).
+    /// Percentages are clamped to that range.
+    ///
+    /// Forward has a little more room than reverse (`129` steps above neutral
+    /// versus `126` below), so each direction is scaled separately, and the
+    /// result is rounded to the nearest speed. `0.0` and `±100.0` are exact.
+    ///
+    /// ```
+    /// # use feedback::Wheels;
+    /// #
+    /// assert!(Wheels::from_percent(0.0, 0.0).is_stopped());
+    ///
+    /// let wheels = Wheels::from_percent(100.0, -100.0);
+    /// assert_eq!((wheels.wheel0, wheels.wheel5), (255, 0));
+    /// assert_eq!((wheels.left_percent(), wheels.right_percent()), (100.0, -100.0));
+    ///
+    /// assert_eq!(Wheels::from_percent(50.0, -50.0).left_signed(), 65);
+    /// ```
+    #[staticmethod]
+    pub fn from_percent(left: f32, right: f32) -> Self {
+        Self::from_signed(percent_to_signed(left), percent_to_signed(right))
+    }
+
+    /// The left side's speed as a percentage of full speed, from `-100.0` to
+    /// `100.0`. See `Wheels::from_percent`.
+    pub fn left_percent(&self) -> f32 {
+        signed_to_percent(self.left_signed())
+    }
+
+    /// The right side's speed as a percentage of full speed, from `-100.0` to
+    /// `100.0`. See `Wheels::from_percent`.
+    pub fn right_percent(&self) -> f32 {
+        signed_to_percent(self.right_signed())
+    }
+
+    /// Whether every wheel is commanded to the neutral speed.
+    ///
+    /// ```
+    /// # use feedback::Wheels;
+    /// #
+    /// assert!(Wheels::from_signed(0, 0).is_stopped());
+    /// assert!(!Wheels::from_signed(0, 10).is_stopped());
+    /// ```
+    pub fn is_stopped(&self) -> bool {
+        [
+            self.wheel0,
+            self.wheel1,
+            self.wheel2,
+            self.wheel3,
+            self.wheel4,
+            self.wheel5,
+        ]
+        .iter()
+        .all(|&speed| speed == Self::NEURTAL_SPEED)
+    }
+
+    /// Calculates the checksum of the current wheel speeds. This is the same as
+    /// `Checksum::checksum`, without needing the trait in scope.
+    ///
+    /// ```
+    /// # use feedback::{checksum::Checksum, Wheels};
+    /// #
+    /// let wheels = Wheels::from_signed(10, -10);
+    /// assert_eq!(wheels.checksum(), Checksum::checksum(&wheels));
+    /// assert_eq!(wheels.checksum(), wheels.checksum);
+    /// ```
+    pub fn checksum(&self) -> u8 {
+        Checksum::checksum(self)
+    }
+
     fn __str__(&self) -> PyResult<String> {
         Ok(format!("{:?}", self))
     }
 }
 
+/// Turns a raw wheel speed into an offset from neutral.
+fn signed_speed(speed: u8) -> i16 {
+    speed as i16 - Wheels::NEURTAL_SPEED as i16
+}
+
+/// Turns an offset from neutral into a raw wheel speed, clamping it to fit.
+fn unsigned_speed(speed: i16) -> u8 {
+    (speed as i32 + Wheels::NEURTAL_SPEED as i32).clamp(0, u8::MAX as i32) as u8
+}
+
+/// How far above neutral a wheel can go.
+const MAX_FORWARD: f32 = (u8::MAX - Wheels::NEURTAL_SPEED) as f32;
+/// How far below neutral a wheel can go.
+const MAX_REVERSE: f32 = Wheels::NEURTAL_SPEED as f32;
+
+/// Turns a percentage of full speed into an offset from neutral.
+fn percent_to_signed(percent: f32) -> i16 {
+    let fraction = percent.clamp(-100.0, 100.0) / 100.0;
+    let range = if fraction >= 0.0 {
+        MAX_FORWARD
+    } else {
+        MAX_REVERSE
+    };
+    (fraction * range).round() as i16
+}
+
+/// Turns an offset from neutral into a percentage of full speed.
+fn signed_to_percent(speed: i16) -> f32 {
+    let range = if speed >= 0 { MAX_FORWARD } else { MAX_REVERSE };
+    speed as f32 / range * 100.0
+}
+
 /// The flashing LED on the top of the Rover
 #[pyclass]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Led {
     pub red: u8,
     pub green: u8,
@@ -83,16 +314,267 @@ pub struct Led {
 impl Led {
     pub const SUBSYSTEM_BYTE: u8 = 0x01;
     pub const PART_BYTE: u8 = 0x02;
+    /// The length of an LED frame, including the subsystem and part bytes.
+    pub const FRAME_LENGTH: u32 = 5;
+
+    /// Whether every channel is zero, which turns the LED off.
+    pub const fn is_off(&self) -> bool {
+        self.red == 0 && self.green == 0 && self.blue == 0
+    }
+
+    /// Guards against turning the status LED off by mistake, like sending a
+    /// default `Led` before the color was set. Returns `LedOff` for an
+    /// all-zero LED unless `allow_off` is set.
+    ///
+    /// Turning the LED off is sometimes intentional, so nothing else checks
+    /// this. Call it before sending where a dark LED would be a problem.
+    ///
+    /// ```
+    /// # use feedback::{error::ParsingError, Led};
+    /// #
+    /// let off = Led { red: 0, green: 0, blue: 0 };
+    /// assert_eq!(off.validate(false), Err(ParsingError::LedOff));
+    /// assert_eq!(off.validate(true), Ok(()));
+    ///
+    /// assert_eq!(Led { red: 0, green: 0, blue: 1 }.validate(false), Ok(()));
+    /// ```
+    pub fn validate(&self, allow_off: bool) -> Result<(), ParsingError> {
+        if self.is_off() && !allow_off {
+            return Err(ParsingError::LedOff);
+        }
+
+        Ok(())
+    }
+
+    /// Unpacks a color from `0x00RRGGBB`: red in the third byte from the
+    /// bottom, then green, then blue in the lowest byte. The top byte is
+    /// ignored.
+    ///
+    /// ```
+    /// # use feedback::Led;
+    /// #
+    /// let red = Led::from_u32(0xFF0000);
+    /// assert_eq!(red, Led { red: 255, green: 0, blue: 0 });
+    /// assert_eq!(red.to_u32(), 0xFF0000);
+    ///
+    /// let green = Led::from_u32(0x00FF00);
+    /// assert_eq!(green, Led { red: 0, green: 255, blue: 0 });
+    /// assert_eq!(green.to_u32(), 0x00FF00);
+    ///
+    /// assert_eq!(Led::from_u32(0xAB0000FF), Led { red: 0, green: 0, blue: 255 });
+    /// ```
+    #[staticmethod]
+    pub const fn from_u32(rgb: u32) -> Led {
+        let [_, red, green, blue] = rgb.to_be_bytes();
+        Led { red, green, blue }
+    }
+
+    /// Packs this color into `0x00RRGGBB`, the reverse of `Led::from_u32`.
+    /// The top byte is always zero.
+    pub const fn to_u32(&self) -> u32 {
+        u32::from_be_bytes([0, self.red, self.green, self.blue])
+    }
+
+    /// Returns this color dimmed by the given factor, from `0.0` (off) to
+    /// `1.0` (unchanged). Factors outside that range are clamped.
+    ///
+    /// Each channel is rounded to the nearest value, with halves rounding up.
+    ///
+    /// ```
+    /// # use feedback::Led;
+    /// #
+    /// let led = Led { red: 255, green: 100, blue: 1 };
+    /// assert_eq!(led.scaled(0.0), Led { red: 0, green: 0, blue: 0 });
+    /// assert_eq!(led.scaled(0.5), Led { red: 128, green: 50, blue: 1 });
+    /// assert_eq!(led.scaled(1.0), led);
+    /// ```
+    pub fn scaled(&self, factor: f32) -> Led {
+        let factor = factor.clamp(0.0, 1.0);
+        let scale = |channel: u8| (channel as f32 * factor).round() as u8;
+
+        Led {
+            red: scale(self.red),
+            green: scale(self.green),
+            blue: scale(self.blue),
+        }
+    }
+
+    /// Returns the color `t` of the way from this color to `to`, where `0.0`
+    /// is this color and `1.0` is `to`. `t` is clamped to that range, and each
+    /// channel is rounded to the nearest value.
+    ///
+    /// ```
+    /// # use feedback::Led;
+    /// #
+    /// let red = Led { red: 255, green: 0, blue: 0 };
+    /// let blue = Led { red: 0, green: 0, blue: 255 };
+    ///
+    /// assert_eq!(red.lerp(&blue, 0.0), red);
+    /// assert_eq!(red.lerp(&blue, 1.0), blue);
+    /// assert_eq!(red.lerp(&blue, 0.5), Led { red: 128, green: 0, blue: 128 });
+    /// ```
+    pub fn lerp(&self, to: &Led, t: f32) -> Led {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+
+        Led {
+            red: mix(self.red, to.red),
+            green: mix(self.green, to.green),
+            blue: mix(self.blue, to.blue),
+        }
+    }
+
+    /// Returns this color with a gamma curve applied to each channel, so
+    /// fades look even to the eye. `2.2` is a good default.
+    ///
+    /// ```
+    /// # use feedback::Led;
+    /// #
+    /// let led = Led { red: 255, green: 128, blue: 0 };
+    /// assert_eq!(led.gamma_corrected(2.2), Led { red: 255, green: 56, blue: 0 });
+    /// ```
+    pub fn gamma_corrected(&self, gamma: f32) -> Led {
+        let correct = |channel: u8| {
+            ((channel as f32 / u8::MAX as f32).powf(gamma) * u8::MAX as f32).round() as u8
+        };
+
+        Led {
+            red: correct(self.red),
+            green: correct(self.green),
+            blue: correct(self.blue),
+        }
+    }
 
     fn __str__(&self) -> PyResult<String> {
         Ok(format!("{:?}", self))
     }
 }
 
+impl Led {
+    /// Returns the colors for fading from this color to `to` in the given
+    /// number of steps. The first color is this one and the last is `to`, so
+    /// there are `steps + 1` colors in total. The exception is zero steps,
+    /// which jumps straight there: the only color is `to`.
+    ///
+    /// ```
+    /// # use feedback::Led;
+    /// #
+    /// let off = Led { red: 0, green: 0, blue: 0 };
+    /// let white = Led { red: 255, green: 255, blue: 255 };
+    ///
+    /// let reds: Vec<u8> = off.fade(white, 4).map(|led| led.red).collect();
+    /// assert_eq!(reds, [0, 64, 128, 191, 255]);
+    /// assert_eq!(off.fade(white, 0).collect::<Vec<_>>(), [white]);
+    /// ```
+    pub fn fade(&self, to: Led, steps: usize) -> Fade {
+        Fade {
+            from: *self,
+            to,
+            step: 0,
+            steps,
+        }
+    }
+}
+
+/// The colors of a fade between two LED colors. See `Led::fade`.
+#[derive(Debug, Clone)]
+pub struct Fade {
+    from: Led,
+    to: Led,
+    step: usize,
+    steps: usize,
+}
+
+impl Iterator for Fade {
+    type Item = Led;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.steps {
+            return None;
+        }
+
+        let t = if self.steps == 0 {
+            1.0
+        } else {
+            self.step as f32 / self.steps as f32
+        };
+
+        self.step += 1;
+        Some(self.from.lerp(&self.to, t))
+    }
+}
+
+/// Reads a color from a config-style string: a name (like `red` or `off`),
+/// `#RRGGBB`, or `rgb(r, g, b)`.
+///
+/// ```
+/// # use feedback::{error::ParsingError, Led};
+/// #
+/// let red = Led { red: 255, green: 0, blue: 0 };
+/// assert_eq!("red".parse(), Ok(red));
+/// assert_eq!("#FF0000".parse(), Ok(red));
+/// assert_eq!("rgb(255, 0, 0)".parse(), Ok(red));
+///
+/// assert_eq!("#00ff7f".parse(), Ok(Led { red: 0, green: 255, blue: 127 }));
+/// assert_eq!("chartreuse".parse::<Led>(), Err(ParsingError::InvalidColor));
+/// assert_eq!("#+f+f+f".parse::<Led>(), Err(ParsingError::InvalidColor));
+/// ```
+impl FromStr for Led {
+    type Err = ParsingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+
+        let (red, green, blue) = match s.as_str() {
+            "red" => (255, 0, 0),
+            "green" => (0, 255, 0),
+            "blue" => (0, 0, 255),
+            "yellow" => (255, 255, 0),
+            "cyan" => (0, 255, 255),
+            "magenta" => (255, 0, 255),
+            "white" => (255, 255, 255),
+            "black" | "off" => (0, 0, 0),
+
+            hex if hex.starts_with('#') => {
+                let hex = &hex[1..];
+                if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(ParsingError::InvalidColor);
+                }
+
+                let channel = |i: usize| {
+                    u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| ParsingError::InvalidColor)
+                };
+                (channel(0)?, channel(2)?, channel(4)?)
+            }
+
+            rgb => {
+                let channels = rgb
+                    .strip_prefix("rgb(")
+                    .and_then(|rest| rest.strip_suffix(')'))
+                    .ok_or(ParsingError::InvalidColor)?
+                    .split(',')
+                    .map(|c| {
+                        c.trim()
+                            .parse::<u8>()
+                            .map_err(|_| ParsingError::InvalidColor)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match channels[..] {
+                    [red, green, blue] => (red, green, blue),
+                    _ => return Err(ParsingError::InvalidColor),
+                }
+            }
+        };
+
+        Ok(Led { red, green, blue })
+    }
+}
+
 /// The little robotic arm on the sticking out of the Rover
 /// old capstooOOOone
 #[pyclass]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Arm {
     pub bicep: u8,
     pub forearm: u8,
@@ -106,16 +588,249 @@ pub struct Arm {
 #[pymethods]
 impl Arm {
     pub const SUBSYSTEM_BYTE: u8 = 0x02;
+    /// The length of an arm frame, including the subsystem byte.
+    ///
+    /// The arm has no part byte, so a frame is the subsystem byte followed by
+    /// `bicep`, `forearm`, `base`, `wrist_pitch`, `wrist_roll`, `claw`, and
+    /// `checksum`, in that order. If the arm ever gets more parts (like a
+    /// separate wrist controller), give it a `PART_BYTE` like `Wheels` has and
+    /// set the `part` of its entries in the parser's frame table.
+    ///
+    /// ```
+    /// # use feedback::{Arm, parse::{parse, Message}};
+    /// #
+    /// let frame = [Arm::SUBSYSTEM_BYTE, 1, 2, 3, 4, 5, 6, 21];
+    /// assert_eq!(frame.len() as u32, Arm::FRAME_LENGTH);
+    ///
+    /// let Ok(Message::Arm(arm)) = parse(&frame) else { panic!("not an arm frame") };
+    /// assert_eq!(
+    ///     [arm.bicep, arm.forearm, arm.base, arm.wrist_pitch, arm.wrist_roll, arm.claw, arm.checksum],
+    ///     [1, 2, 3, 4, 5, 6, 21],
+    /// );
+    /// ```
+    pub const FRAME_LENGTH: u32 = 8;
+
+    /// The `claw` value that opens the claw all the way.
+    ///
+    /// Provisional: nothing here says which end of the range opens the claw.
+    /// This assumes the top end, and needs checking against the arm's
+    /// firmware. `Arm::with_claw` uses it, so fixing it here fixes both.
+    pub const CLAW_OPEN: u8 = 255;
+    /// The `claw` value that closes the claw all the way. Provisional, like
+    /// `Arm::CLAW_OPEN`.
+    pub const CLAW_CLOSED: u8 = 0;
+
+    /// Returns the poses for moving the arm from `from` to `to` in the given
+    /// number of steps, with each joint moving in a straight line. The first
+    /// pose is `from` and the last is `to`, so there are `steps + 1` poses, and
+    /// each has its checksum computed. The exception is zero steps, which
+    /// jumps straight there: the only pose is `to`.
+    ///
+    /// ```
+    /// # use feedback::Arm;
+    /// #
+    /// let from = Arm { bicep: 0, forearm: 10, base: 126, wrist_pitch: 0, wrist_roll: 0, claw: 0, checksum: 136 };
+    /// let to = Arm { bicep: 100, forearm: 10, base: 126, wrist_pitch: 0, wrist_roll: 0, claw: 0, checksum: 236 };
+    ///
+    /// let poses = Arm::interpolate(&from, &to, 4);
+    /// let biceps: Vec<u8> = poses.iter().map(|arm| arm.bicep).collect();
+    /// assert_eq!(biceps, [0, 25, 50, 75, 100]);
+    /// assert!(poses.iter().all(|arm| arm.forearm == 10 && arm.checksum == arm.bicep + 136));
+    /// assert_eq!(Arm::interpolate(&from, &to, 0), [to]);
+    ///
+    /// // no joint moves more than 30 per step
+    /// assert_eq!(Arm::interpolate_limited(&from, &to, 30).len(), 5);
+    /// ```
+    #[staticmethod]
+    pub fn interpolate(from: &Arm, to: &Arm, steps: usize) -> Vec<Arm> {
+        (0..=steps)
+            .map(|step| {
+                let t = if steps == 0 {
+                    1.0
+                } else {
+                    step as f32 / steps as f32
+                };
+                let mix =
+                    |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+
+                let mut arm = Arm {
+                    bicep: mix(from.bicep, to.bicep),
+                    forearm: mix(from.forearm, to.forearm),
+                    base: mix(from.base, to.base),
+                    wrist_pitch: mix(from.wrist_pitch, to.wrist_pitch),
+                    wrist_roll: mix(from.wrist_roll, to.wrist_roll),
+                    claw: mix(from.claw, to.claw),
+                    checksum: 0,
+                };
+                arm.checksum = arm.checksum();
+                arm
+            })
+            .collect()
+    }
+
+    /// Like `Arm::interpolate`, but uses as few steps as it can without any
+    /// joint moving more than `max_delta` between poses. A `max_delta` of zero
+    /// would never get anywhere, so it's treated as one.
+    #[staticmethod]
+    pub fn interpolate_limited(from: &Arm, to: &Arm, max_delta: u8) -> Vec<Arm> {
+        let largest_move = from
+            .to_checksum_array()
+            .iter()
+            .zip(to.to_checksum_array())
+            .map(|(&from, to)| from.abs_diff(to))
+            .max()
+            .unwrap_or(0);
+
+        let steps = (largest_move as usize).div_ceil(max_delta.max(1) as usize);
+        Self::interpolate(from, to, steps)
+    }
+
+    /// Returns a copy of this arm with its claw set to the given state, and
+    /// the checksum recomputed to match.
+    ///
+    /// ```
+    /// # use feedback::{Arm, ClawState};
+    /// #
+    /// let arm = Arm { bicep: 1, forearm: 2, base: 3, wrist_pitch: 4, wrist_roll: 5, claw: 6, checksum: 21 };
+    ///
+    /// let open = arm.with_claw(ClawState::Open);
+    /// assert_eq!((open.claw, open.checksum), (255, 14));
+    ///
+    /// let closed = arm.with_claw(ClawState::Closed);
+    /// assert_eq!((closed.claw, closed.checksum), (0, 15));
+    /// ```
+    pub fn with_claw(&self, state: ClawState) -> Arm {
+        let mut arm = *self;
+        arm.claw = match state {
+            ClawState::Open => Self::CLAW_OPEN,
+            ClawState::Closed => Self::CLAW_CLOSED,
+        };
+        arm.checksum = arm.checksum();
+        arm
+    }
+
+    /// Approximates where the claw is, as `(x, y, z)` in the same units as the
+    /// geometry's link lengths. `x` points forward, `y` to the left, and `z`
+    /// up, from the point on the ground under the base.
+    ///
+    /// Each joint byte maps linearly onto its range of motion:
+    ///
+    /// - `base` turns the arm from 90° right (0) to 90° left (255).
+    /// - `bicep` raises the bicep from pointing forward (0), through straight
+    ///   up, to pointing backward (255).
+    /// - `forearm` bends the forearm relative to the bicep, from 90° down (0)
+    ///   to 90° up (255), so it's in line with the bicep in the middle.
+    ///
+    /// The wrist and claw are ignored, so this is where the wrist is.
+    ///
+    /// ```
+    /// # use feedback::{Arm, ArmGeometry};
+    /// #
+    /// let geometry = ArmGeometry::new(0.5, 1.0, 1.0);
+    ///
+    /// // bicep forward, forearm hanging straight down, turned all the way left
+    /// let arm = Arm { bicep: 0, forearm: 0, base: 255, wrist_pitch: 0, wrist_roll: 0, claw: 0, checksum: 255 };
+    /// let (x, y, z) = arm.end_effector_position(&geometry);
+    ///
+    /// assert!(x.abs() < 1e-9);
+    /// assert!((y - 1.0).abs() < 1e-9);
+    /// assert!((z + 0.5).abs() < 1e-9);
+    /// ```
+    pub fn end_effector_position(&self, links: &ArmGeometry) -> (f64, f64, f64) {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let angle = |byte: u8, min: f64, max: f64| min + (max - min) * byte as f64 / 255.0;
+        let yaw = angle(self.base, -FRAC_PI_2, FRAC_PI_2);
+        let shoulder = angle(self.bicep, 0.0, PI);
+        let elbow = shoulder + angle(self.forearm, -FRAC_PI_2, FRAC_PI_2);
+
+        let reach = links.bicep * shoulder.cos() + links.forearm * elbow.cos();
+        let height =
+            links.shoulder_height + links.bicep * shoulder.sin() + links.forearm * elbow.sin();
+
+        (reach * yaw.cos(), reach * yaw.sin(), height)
+    }
+
+    /// Calculates the checksum of the current joint values. This is the same
+    /// as `Checksum::checksum`, without needing the trait in scope.
+    ///
+    /// ```
+    /// # use feedback::{checksum::Checksum, Arm};
+    /// #
+    /// let arm = Arm { bicep: 1, forearm: 2, base: 3, wrist_pitch: 4, wrist_roll: 5, claw: 6, checksum: 0 };
+    /// assert_eq!(arm.checksum(), Checksum::checksum(&arm));
+    /// assert_eq!(arm.checksum(), 21);
+    /// ```
+    pub fn checksum(&self) -> u8 {
+        Checksum::checksum(self)
+    }
 
     fn __str__(&self) -> PyResult<String> {
         Ok(format!("{:?}", self))
     }
 }
 
+/// Whether the arm's claw should be open or closed.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClawState {
+    Open,
+    Closed,
+}
+
+/// The lengths of the arm's links, for `Arm::end_effector_position`. All three
+/// are in the same unit, which the position comes back in.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArmGeometry {
+    /// How high the shoulder (where the bicep meets the base) is off the
+    /// ground.
+    pub shoulder_height: f64,
+    /// From the shoulder to the elbow.
+    pub bicep: f64,
+    /// From the elbow to the wrist.
+    pub forearm: f64,
+}
+
+#[pymethods]
+impl ArmGeometry {
+    #[new]
+    pub const fn new(shoulder_height: f64, bicep: f64, forearm: f64) -> Self {
+        Self {
+            shoulder_height,
+            bicep,
+            forearm,
+        }
+    }
+}
+
 /// The science package on the Rover, including the utilities needed to perform
 /// field experiments.
+///
+/// This is what the science package is told to do. The actuator bytes are
+/// speeds, like a wheel's: `Wheels::NEURTAL_SPEED` holds still, higher values
+/// lower the drill, and lower values raise it. They're not positions, so a
+/// command doesn't say where the actuators will end up.
+///
+/// What the science package reports back is a separate `ScienceTelemetry`,
+/// with its own subsystem byte, so a parsed message always says which
+/// direction it went. Both round-trip through `parse` and `Message::encode`:
+///
+/// ```
+/// # use feedback::{parse::{parse, Message}, Science, ScienceTelemetry};
+/// #
+/// let command = Science::new(40, 0, 20, 3, 0, 63).unwrap();
+/// let frame = Message::Science(command).encode();
+/// assert_eq!(frame, [0x03, 40, 0, 20, 3, 0, 63]);
+/// assert_eq!(parse(&frame), Ok(Message::Science(command)));
+///
+/// let reported = ScienceTelemetry { big_actuator: 40, small_actuator: 20, checksum: 60 };
+/// let frame = Message::ScienceTelemetry(reported).encode();
+/// assert_eq!(frame, [0x04, 40, 20, 60]);
+/// assert_eq!(parse(&frame), Ok(Message::ScienceTelemetry(reported)));
+/// ```
 #[pyclass]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Science {
     big_actuator: u8,
     drill: u8,
@@ -128,6 +843,183 @@ pub struct Science {
 #[pymethods]
 impl Science {
     pub const SUBSYSTEM_BYTE: u8 = 0x03;
+    /// The length of a science frame, including the subsystem byte.
+    pub const FRAME_LENGTH: u32 = 7;
+    /// How many test tubes the science package has. `test_tubes` selects one
+    /// of them, so it must be below this.
+    ///
+    /// Provisional: nothing here says how many tubes the carousel holds. This
+    /// needs checking against the science package's hardware, and changing it
+    /// changes which commands `Science::validate` accepts.
+    pub const TEST_TUBE_COUNT: u8 = 12;
+
+    /// Creates a new `Science` object from the given values, checking that
+    /// they're in range. The checksum isn't checked.
+    ///
+    /// ```
+    /// # use feedback::{error::ParsingError, Science};
+    /// #
+    /// assert!(Science::new(0, 0, 0, 11, 0, 11).is_ok());
+    /// assert_eq!(
+    ///     Science::new(0, 0, 0, 12, 0, 12).unwrap_err(),
+    ///     ParsingError::OutOfRange { field: "test_tubes", value: 12, max: 11 }
+    /// );
+    /// ```
+    #[new]
+    pub fn new(
+        big_actuator: u8,
+        drill: u8,
+        small_actuator: u8,
+        test_tubes: u8,
+        camera_servo: u8,
+        checksum: u8,
+    ) -> Result<Self, ParsingError> {
+        let science = Self {
+            big_actuator,
+            drill,
+            small_actuator,
+            test_tubes,
+            camera_servo,
+            checksum,
+        };
+
+        science.validate()?;
+        Ok(science)
+    }
+
+    /// Checks that every field is in range.
+    pub fn validate(&self) -> Result<(), ParsingError> {
+        if self.test_tubes >= Self::TEST_TUBE_COUNT {
+            return Err(ParsingError::OutOfRange {
+                field: "test_tubes",
+                value: self.test_tubes,
+                max: Self::TEST_TUBE_COUNT - 1,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Calculates the checksum of the current values. This is the same as
+    /// `Checksum::checksum`, without needing the trait in scope.
+    ///
+    /// ```
+    /// # use feedback::{checksum::Checksum, Science};
+    /// #
+    /// let science = Science::new(1, 2, 3, 4, 5, 0).unwrap();
+    /// assert_eq!(science.checksum(), Checksum::checksum(&science));
+    /// assert_eq!(science.checksum(), 15);
+    /// ```
+    pub fn checksum(&self) -> u8 {
+        Checksum::checksum(self)
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self))
+    }
+}
+
+/// Where the science package's actuators actually are, as reported by the
+/// Rover. Unlike `Science`, which is sent to the Rover, this is only ever sent
+/// from the Rover back to the base station.
+///
+/// These are positions, not the speeds a `Science` command sends, so there's
+/// no way to tell from a command alone what the telemetry should say.
+///
+/// Provisional: the subsystem byte and the frame layout,
+/// `[0x04, big_actuator, small_actuator, checksum]`, are this crate's guess,
+/// and need confirming against the science package's firmware.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScienceTelemetry {
+    pub big_actuator: u8,
+    pub small_actuator: u8,
+    /// The sum of the reported actuator positions.
+    pub checksum: u8,
+}
+
+#[pymethods]
+impl ScienceTelemetry {
+    /// Provisional, like the rest of the layout. See `ScienceTelemetry`.
+    pub const SUBSYSTEM_BYTE: u8 = 0x04;
+    /// The length of a science telemetry frame, including the subsystem byte.
+    pub const FRAME_LENGTH: u32 = 4;
+
+    /// Calculates the checksum of the reported positions. This is the same as
+    /// `Checksum::checksum`, without needing the trait in scope.
+    pub fn checksum(&self) -> u8 {
+        Checksum::checksum(self)
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self))
+    }
+}
+
+/// The E-box's reply to a command, saying whether it was accepted. Only ever
+/// sent from the Rover to the base station.
+///
+/// The frame is `[0x05, sequence, status]`.
+///
+/// Provisional: the subsystem byte, the frame layout, and `Ack::STATUS_OK` are
+/// this crate's guess, and need confirming against the E-box's firmware.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ack {
+    /// The sequence number of the command being acknowledged.
+    pub sequence: u8,
+    /// Whether the command was applied. See `Ack::STATUS_OK`.
+    pub status: u8,
+}
+
+#[pymethods]
+impl Ack {
+    /// Provisional, like the rest of the layout. See `Ack`.
+    pub const SUBSYSTEM_BYTE: u8 = 0x05;
+    /// The length of an ack frame, including the subsystem byte.
+    pub const FRAME_LENGTH: u32 = 3;
+
+    /// The status sent when a command was applied. Any other status means it
+    /// was rejected. Provisional, like the rest of the layout.
+    pub const STATUS_OK: u8 = 0x00;
+
+    /// Whether the acknowledged command was applied.
+    pub fn is_ok(&self) -> bool {
+        self.status == Self::STATUS_OK
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(format!("{:?}", self))
+    }
+}
+
+/// A message from a subsystem this crate doesn't know about, either decoded by
+/// a decoder registered in a [`ParserRegistry`] or passed through raw by
+/// [`parse_lenient`].
+///
+/// [`ParserRegistry`]: crate::registry::ParserRegistry
+/// [`parse_lenient`]: crate::parse::parse_lenient
+#[pyclass]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomMessage {
+    pub subsystem: u8,
+    /// The whole frame, including the subsystem byte.
+    pub bytes: Vec<u8>,
+}
+
+#[pymethods]
+impl CustomMessage {
+    /// Creates a new `CustomMessage` holding a copy of the given frame. The
+    /// subsystem is read from its first byte.
+    ///
+    /// Returns `None` if the frame is empty.
+    #[staticmethod]
+    pub fn from_frame(frame: &[u8]) -> Option<Self> {
+        Some(Self {
+            subsystem: *frame.first()?,
+            bytes: frame.to_vec(),
+        })
+    }
 
     fn __str__(&self) -> PyResult<String> {
         Ok(format!("{:?}", self))
@@ -139,8 +1031,16 @@ fn feedback(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Wheels>()?;
     m.add_class::<Led>()?;
     m.add_class::<Arm>()?;
+    m.add_class::<ClawState>()?;
+    m.add_class::<ArmGeometry>()?;
     m.add_class::<Science>()?;
+    m.add_class::<ScienceTelemetry>()?;
+    m.add_class::<Ack>()?;
+    m.add_class::<CustomMessage>()?;
     m.add_class::<parse::PyMessage>()?;
     m.add_function(wrap_pyfunction!(parse::pyparse, m)?)?;
+    m.add_function(wrap_pyfunction!(parse::pyencode, m)?)?;
+    m.add_function(wrap_pyfunction!(parse::explain, m)?)?;
+    m.add_function(wrap_pyfunction!(parse::pyreference_vectors, m)?)?;
     Ok(())
 }
@@ -1,29 +1,464 @@
 //! # Parse
 //!
-//! A module that parses a given slice into a valid message.
+//! A module that parses a given slice into a valid message, and encodes
+//! messages back into bytes.
 
-use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
 
-use crate::{error::ParsingError, Arm, Led, Science, Wheels};
+use crate::{error::ParsingError, Ack, Arm, CustomMessage, Led, Science, ScienceTelemetry, Wheels};
 
 /// Any kind of message that should be sent to/from the rover.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Message {
     Wheels(Wheels),
     Led(Led),
     Arm(Arm),
     Science(Science),
+    ScienceTelemetry(ScienceTelemetry),
+    Ack(Ack),
+    /// A message from a subsystem registered in a [`ParserRegistry`], or an
+    /// unknown subsystem passed through by [`parse_lenient`].
+    ///
+    /// [`ParserRegistry`]: crate::registry::ParserRegistry
+    Custom(CustomMessage),
+}
+
+impl Message {
+    /// The subsystem byte this message is sent under.
+    pub const fn subsystem_byte(&self) -> u8 {
+        match self {
+            Message::Wheels(_) => Wheels::SUBSYSTEM_BYTE,
+            Message::Led(_) => Led::SUBSYSTEM_BYTE,
+            Message::Arm(_) => Arm::SUBSYSTEM_BYTE,
+            Message::Science(_) => Science::SUBSYSTEM_BYTE,
+            Message::ScienceTelemetry(_) => ScienceTelemetry::SUBSYSTEM_BYTE,
+            Message::Ack(_) => Ack::SUBSYSTEM_BYTE,
+            Message::Custom(custom) => custom.subsystem,
+        }
+    }
+
+    /// Encodes this message into the bytes that go on the wire. This is the
+    /// inverse of `parse`.
+    ///
+    /// ```
+    /// # use feedback::parse::parse;
+    /// #
+    /// let frame = [0x02, 1, 2, 3, 4, 5, 6, 21];
+    /// assert_eq!(parse(&frame).unwrap().encode(), frame);
+    /// ```
+    pub fn encode(&self) -> Vec<u8> {
+        let Some((layout, payload)) = self.layout_and_payload() else {
+            // only custom messages have no layout, and they keep their whole frame
+            return match self {
+                Message::Custom(custom) => custom.bytes.clone(),
+                _ => Vec::new(),
+            };
+        };
+
+        let mut frame = Vec::with_capacity(layout.length as usize);
+        frame.push(layout.subsystem);
+        frame.extend(layout.part);
+        frame.extend(payload);
+        frame
+    }
+
+    /// The layout this message is encoded with, and its payload in that
+    /// layout's field order. Custom messages have no layout.
+    fn layout_and_payload(&self) -> Option<(&'static FrameLayout, Vec<u8>)> {
+        FRAMES
+            .iter()
+            .find_map(|layout| Some((layout, (layout.encode)(self)?)))
+    }
+
+    /// Encodes this message into a `Bytes`, which can be shared between tasks
+    /// and cloned without copying the frame.
+    ///
+    /// ```
+    /// # use feedback::parse::parse;
+    /// #
+    /// let message = parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+    /// assert_eq!(message.to_bytes(), message.encode());
+    /// ```
+    #[cfg(feature = "bytes")]
+    pub fn to_bytes(&self) -> bytes::Bytes {
+        bytes::Bytes::from(self.encode())
+    }
+
+    /// Encodes this message and writes the frame to an async writer, like a
+    /// file or TCP stream.
+    ///
+    /// ```
+    /// # use feedback::parse::parse;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> std::io::Result<()> {
+    /// let message = parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+    ///
+    /// let mut written = Vec::new();
+    /// message.write_to(&mut written).await?;
+    /// message.write_to(&mut written).await?;
+    ///
+    /// assert_eq!(written, [message.encode(), message.encode()].concat());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn write_to<W>(&self, w: &mut W) -> std::io::Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt as _;
+
+        w.write_all(&self.encode()).await
+    }
+
+    /// How many bytes `encode` will produce for this message, without
+    /// encoding it.
+    pub fn encoded_len(&self) -> usize {
+        (match self {
+            Message::Wheels(_) => Wheels::FRAME_LENGTH,
+            Message::Led(_) => Led::FRAME_LENGTH,
+            Message::Arm(_) => Arm::FRAME_LENGTH,
+            Message::Science(_) => Science::FRAME_LENGTH,
+            Message::ScienceTelemetry(_) => ScienceTelemetry::FRAME_LENGTH,
+            Message::Ack(_) => Ack::FRAME_LENGTH,
+            Message::Custom(custom) => return custom.bytes.len(),
+        }) as usize
+    }
+
+    /// Runs every check that applies to this kind of message: the checksum,
+    /// and the range of any field that has one. `parse` only checks lengths,
+    /// so call this before trusting a parsed message.
+    ///
+    /// LED, ack, and custom messages have no checksum or ranges this crate
+    /// knows about, so they're always valid.
+    ///
+    /// ```
+    /// # use feedback::{error::ParsingError, parse::{frame_layout, parse, reference_vectors}};
+    /// #
+    /// for (name, mut frame, message) in reference_vectors() {
+    ///     assert_eq!(message.validate(), Ok(()), "{name}");
+    ///
+    ///     // with its checksum off by one, a frame that has one fails
+    ///     if let Some(offset) = frame_layout(frame[0], frame.get(1).copied()).unwrap().checksum_offset() {
+    ///         frame[offset] = frame[offset].wrapping_add(1);
+    ///         let error = parse(&frame).unwrap().validate();
+    ///         assert!(matches!(error, Err(ParsingError::BadChecksum { .. })), "{name}");
+    ///     }
+    /// }
+    ///
+    /// let no_such_tube = parse(&[0x03, 0, 0, 0, 12, 0, 12]).unwrap();
+    /// assert!(matches!(no_such_tube.validate(), Err(ParsingError::OutOfRange { field: "test_tubes", .. })));
+    /// ```
+    pub fn validate(&self) -> Result<(), ParsingError> {
+        let Some((layout, payload)) = self.layout_and_payload() else {
+            return Ok(());
+        };
+        layout.check_checksum(&payload)?;
+
+        match self {
+            Message::Science(science) => science.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Recomputes and stores the checksum, for messages that have one. Call
+    /// this after changing any field. LED, ack, and custom messages have no
+    /// checksum, so they're left alone.
+    ///
+    /// ```
+    /// # use feedback::parse::{parse, Message};
+    /// #
+    /// let mut message = parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+    /// if let Message::Arm(arm) = &mut message {
+    ///     arm.claw = 100;
+    /// }
+    /// assert!(message.validate().is_err());
+    ///
+    /// message.recompute_checksum();
+    /// assert_eq!(message.validate(), Ok(()));
+    /// ```
+    pub fn recompute_checksum(&mut self) {
+        let Some((layout, mut payload)) = self.layout_and_payload() else {
+            return;
+        };
+        let Some(i) = layout.checksum_field() else {
+            return;
+        };
+
+        payload[i] = sum_except(&payload, i);
+        if let Ok(message) = (layout.decode)(&payload) {
+            *self = message;
+        }
+    }
+
+    /// Describes this message's kind and field names, with every value
+    /// replaced by `*`. Useful for sharing protocol traces without sharing the
+    /// commands themselves.
+    ///
+    /// ```
+    /// # use feedback::parse::parse;
+    /// #
+    /// let led = parse(&[0x01, 0x02, 255, 0, 0]).unwrap();
+    /// assert_eq!(led.redacted(), "Led { red: *, green: *, blue: * }");
+    ///
+    /// let arm = parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+    /// assert!(!arm.redacted().chars().any(|c| c.is_ascii_digit()));
+    ///
+    /// let telemetry = parse(&[0x04, 40, 2, 42]).unwrap();
+    /// assert_eq!(telemetry.redacted(), "ScienceTelemetry { big_actuator: *, small_actuator: *, checksum: * }");
+    /// ```
+    pub fn redacted(&self) -> String {
+        let (name, fields) = match self.layout_and_payload() {
+            Some((layout, _)) => (format!("{:?}", self.kind()), layout.fields),
+            None => ("CustomMessage".to_string(), &["subsystem", "bytes"][..]),
+        };
+
+        let fields: Vec<String> = fields.iter().map(|field| format!("{field}: *")).collect();
+        format!("{name} {{ {} }}", fields.join(", "))
+    }
+
+    /// Passes the contained value to the visitor method for its kind.
+    pub fn accept<V: MessageVisitor>(&self, visitor: &mut V) -> V::Output {
+        match self {
+            Message::Wheels(wheels) => visitor.visit_wheels(wheels),
+            Message::Led(led) => visitor.visit_led(led),
+            Message::Arm(arm) => visitor.visit_arm(arm),
+            Message::Science(science) => visitor.visit_science(science),
+            Message::ScienceTelemetry(telemetry) => visitor.visit_science_telemetry(telemetry),
+            Message::Ack(ack) => visitor.visit_ack(ack),
+            Message::Custom(custom) => visitor.visit_custom(custom),
+        }
+    }
+
+    /// Checks if two messages carry the same payload, without looking at
+    /// their checksums.
+    ///
+    /// Useful when coalescing a burst of commands, since a stale checksum
+    /// shouldn't make two otherwise-identical commands look different.
+    ///
+    /// ```
+    /// # use feedback::parse::parse;
+    /// #
+    /// let a = parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+    /// let stale = parse(&[0x02, 1, 2, 3, 4, 5, 6, 0]).unwrap();
+    /// let moved = parse(&[0x02, 1, 2, 3, 4, 5, 7, 22]).unwrap();
+    ///
+    /// assert!(a.eq_ignoring_checksum(&stale));
+    /// assert!(!a.eq_ignoring_checksum(&moved));
+    /// ```
+    pub fn eq_ignoring_checksum(&self, other: &Message) -> bool {
+        match (self.layout_and_payload(), other.layout_and_payload()) {
+            (Some((layout, mut a)), Some((other_layout, mut b)))
+                if (layout.subsystem, layout.part)
+                    == (other_layout.subsystem, other_layout.part) =>
+            {
+                if let Some(i) = layout.checksum_field() {
+                    (a[i], b[i]) = (0, 0);
+                }
+                a == b
+            }
+            // custom messages have no layout, so compare them whole
+            (None, None) => self == other,
+            _ => false,
+        }
+    }
+
+    /// Which kind of message this is.
+    pub const fn kind(&self) -> MessageKind {
+        match self {
+            Message::Wheels(_) => MessageKind::Wheels,
+            Message::Led(_) => MessageKind::Led,
+            Message::Arm(_) => MessageKind::Arm,
+            Message::Science(_) => MessageKind::Science,
+            Message::ScienceTelemetry(_) => MessageKind::ScienceTelemetry,
+            Message::Ack(_) => MessageKind::Ack,
+            Message::Custom(_) => MessageKind::Custom,
+        }
+    }
+
+    /// Checks if this message is selected by the given filter.
+    ///
+    /// ```
+    /// # use feedback::parse::{parse, Message, MessageFilter};
+    /// # use feedback::Arm;
+    /// #
+    /// let messages = [
+    ///     parse(&[0x01, 0x02, 255, 0, 0]).unwrap(),
+    ///     parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap(),
+    ///     parse(&[0x03, 1, 2, 3, 4, 5, 15]).unwrap(),
+    ///     parse(&[0x02, 6, 5, 4, 3, 2, 1, 21]).unwrap(),
+    /// ];
+    ///
+    /// let arm_only = MessageFilter::Subsystems(vec![Arm::SUBSYSTEM_BYTE]);
+    /// let arms: Vec<_> = messages.iter().filter(|m| m.matches(&arm_only)).collect();
+    /// assert_eq!(arms.len(), 2);
+    /// assert!(arms.iter().all(|m| matches!(m, Message::Arm(_))));
+    ///
+    /// let red = MessageFilter::Custom(Box::new(|m| matches!(m, Message::Led(led) if led.red == 255)));
+    /// assert_eq!(messages.iter().filter(|m| m.matches(&red)).count(), 1);
+    /// ```
+    pub fn matches(&self, filter: &MessageFilter) -> bool {
+        match filter {
+            MessageFilter::Subsystems(subsystems) => subsystems.contains(&self.subsystem_byte()),
+            MessageFilter::Custom(predicate) => predicate(self),
+        }
+    }
+}
+
+/// Everything teleop sends on each tick, bundled together so no part of it
+/// gets forgotten.
+///
+/// ```
+/// # use feedback::{parse::{parse, DriveCommand}, Arm, Led, Wheels};
+/// #
+/// let command = DriveCommand {
+///     wheels: Wheels::from_signed(20, 20),
+///     arm: Arm { bicep: 1, forearm: 2, base: 3, wrist_pitch: 4, wrist_roll: 5, claw: 6, checksum: 21 },
+///     led: Led { red: 0, green: 255, blue: 0 },
+/// };
+///
+/// let subsystems: Vec<u8> = command.messages().iter().map(|m| m.subsystem_byte()).collect();
+/// assert_eq!(subsystems, [Wheels::SUBSYSTEM_BYTE, Arm::SUBSYSTEM_BYTE, Led::SUBSYSTEM_BYTE]);
+///
+/// let bytes = command.encode();
+/// assert_eq!(bytes.len(), 9 + 8 + 5);
+/// assert!(parse(&bytes[..9]).is_ok() && parse(&bytes[9..17]).is_ok() && parse(&bytes[17..]).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriveCommand {
+    pub wheels: Wheels,
+    pub arm: Arm,
+    pub led: Led,
+}
+
+impl DriveCommand {
+    /// The messages making up this command, in the order they should be sent.
+    pub fn messages(&self) -> [Message; 3] {
+        [
+            Message::Wheels(self.wheels),
+            Message::Arm(self.arm),
+            Message::Led(self.led),
+        ]
+    }
+
+    /// Encodes each message in this command, back to back.
+    pub fn encode(&self) -> Vec<u8> {
+        self.messages().iter().flat_map(Message::encode).collect()
+    }
+}
+
+/// Handles each kind of `Message` in its own method. See `Message::accept`.
+///
+/// Every method is required, so adding a new kind of message means every
+/// visitor stops compiling until it handles it. Prefer this to a `match` with
+/// a catch-all arm when each kind needs its own handling.
+///
+/// ```
+/// # use feedback::{parse::{reference_vectors, MessageKind, MessageVisitor}, Ack, Arm, CustomMessage, Led, Science, ScienceTelemetry, Wheels};
+/// #
+/// struct Kind;
+///
+/// impl MessageVisitor for Kind {
+///     type Output = MessageKind;
+///
+///     fn visit_wheels(&mut self, _: &Wheels) -> Self::Output { MessageKind::Wheels }
+///     fn visit_led(&mut self, _: &Led) -> Self::Output { MessageKind::Led }
+///     fn visit_arm(&mut self, _: &Arm) -> Self::Output { MessageKind::Arm }
+///     fn visit_science(&mut self, _: &Science) -> Self::Output { MessageKind::Science }
+///     fn visit_science_telemetry(&mut self, _: &ScienceTelemetry) -> Self::Output { MessageKind::ScienceTelemetry }
+///     fn visit_ack(&mut self, _: &Ack) -> Self::Output { MessageKind::Ack }
+///     fn visit_custom(&mut self, _: &CustomMessage) -> Self::Output { MessageKind::Custom }
+/// }
+///
+/// for (name, _, message) in reference_vectors() {
+///     assert_eq!(message.accept(&mut Kind), message.kind(), "{name}");
+/// }
+/// ```
+pub trait MessageVisitor {
+    type Output;
+
+    fn visit_wheels(&mut self, wheels: &Wheels) -> Self::Output;
+    fn visit_led(&mut self, led: &Led) -> Self::Output;
+    fn visit_arm(&mut self, arm: &Arm) -> Self::Output;
+    fn visit_science(&mut self, science: &Science) -> Self::Output;
+    fn visit_science_telemetry(&mut self, telemetry: &ScienceTelemetry) -> Self::Output;
+    fn visit_ack(&mut self, ack: &Ack) -> Self::Output;
+    fn visit_custom(&mut self, custom: &CustomMessage) -> Self::Output;
+}
+
+/// Picks out the messages a consumer cares about. See `Message::matches`.
+pub enum MessageFilter {
+    /// Selects messages sent under any of these subsystem bytes.
+    ///
+    /// Wheels and LED messages share a subsystem byte, so selecting one selects both.
+    Subsystems(Vec<u8>),
+    /// Selects messages that the closure returns `true` for.
+    Custom(Box<dyn Fn(&Message) -> bool + Send + Sync>),
+}
+
+/// Which kind of message a `Message` is, without its contents. Cheap to copy
+/// and compare, so it works well as a map key or metrics label.
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use feedback::{parse::{parse, reference_vectors, Message, MessageKind}, Arm, CustomMessage};
+/// #
+/// assert_eq!(parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap().kind(), MessageKind::Arm);
+///
+/// // every kind of message has its own kind, sent under its subsystem byte
+/// let vectors = reference_vectors();
+/// let kinds: HashSet<MessageKind> = vectors.iter().map(|(_, _, m)| m.kind()).collect();
+/// assert_eq!(kinds.len(), vectors.len());
+/// for (name, _, message) in &vectors {
+///     assert_eq!(message.kind().subsystem_byte(), Some(message.subsystem_byte()), "{name}");
+/// }
+///
+/// let custom = Message::Custom(CustomMessage::from_frame(&[0x42]).unwrap());
+/// assert_eq!(custom.kind(), MessageKind::Custom);
+///
+/// assert_eq!(MessageKind::Arm.subsystem_byte(), Some(Arm::SUBSYSTEM_BYTE));
+/// assert_eq!(MessageKind::Custom.subsystem_byte(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageKind {
+    Wheels,
+    Led,
+    Arm,
+    Science,
+    ScienceTelemetry,
+    Ack,
+    Custom,
+}
+
+impl MessageKind {
+    /// The subsystem byte messages of this kind are sent under. Custom
+    /// messages can use any subsystem byte, so they have `None`.
+    pub const fn subsystem_byte(&self) -> Option<u8> {
+        Some(match self {
+            MessageKind::Wheels => Wheels::SUBSYSTEM_BYTE,
+            MessageKind::Led => Led::SUBSYSTEM_BYTE,
+            MessageKind::Arm => Arm::SUBSYSTEM_BYTE,
+            MessageKind::Science => Science::SUBSYSTEM_BYTE,
+            MessageKind::ScienceTelemetry => ScienceTelemetry::SUBSYSTEM_BYTE,
+            MessageKind::Ack => Ack::SUBSYSTEM_BYTE,
+            MessageKind::Custom => return None,
+        })
+    }
 }
 
 /// A PyO3-friendly version of the `Message` enum.
 #[doc(hidden)]
 #[pyclass]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum PyMessage {
     Wheels { wheels: Wheels },
     Led { led: Led },
     Arm { arm: Arm },
     Science { science: Science },
+    ScienceTelemetry { telemetry: ScienceTelemetry },
+    Ack { ack: Ack },
+    Custom { custom: CustomMessage },
 }
 
 impl PyMessage {
@@ -41,6 +476,9 @@ impl From<PyMessage> for Message {
             PyMessage::Led { led } => Message::Led(led),
             PyMessage::Arm { arm } => Message::Arm(arm),
             PyMessage::Science { science } => Message::Science(science),
+            PyMessage::ScienceTelemetry { telemetry } => Message::ScienceTelemetry(telemetry),
+            PyMessage::Ack { ack } => Message::Ack(ack),
+            PyMessage::Custom { custom } => Message::Custom(custom),
         }
     }
 }
@@ -53,108 +491,829 @@ impl From<Message> for PyMessage {
             Message::Led(led) => PyMessage::Led { led },
             Message::Arm(arm) => PyMessage::Arm { arm },
             Message::Science(science) => PyMessage::Science { science },
+            Message::ScienceTelemetry(telemetry) => PyMessage::ScienceTelemetry { telemetry },
+            Message::Ack(ack) => PyMessage::Ack { ack },
+            Message::Custom(custom) => PyMessage::Custom { custom },
+        }
+    }
+}
+
+/// Python sees every `ParsingError` as a `ValueError` with the error's message.
+impl From<ParsingError> for PyErr {
+    fn from(value: ParsingError) -> Self {
+        PyValueError::new_err(value.to_string())
+    }
+}
+
+/// The part reported in errors for subsystems that don't have a part byte,
+/// like the arm and science package.
+pub const NO_PART: u8 = 0x00;
+
+/// The layout of one kind of frame: which bytes select it, how long it must
+/// be, what each byte means, and how to turn it into a `Message` and back.
+///
+/// Every frame starts with its subsystem byte, then its part byte if it has
+/// one. The rest is payload, one byte per entry in `fields`. If one of the
+/// fields is named `"checksum"`, it's the sum of all the other payload bytes.
+///
+/// `Message::validate`, `verify_frame`, and the schema find each byte by its
+/// position in `fields`, but the decoder and encoder list the bytes in their
+/// own order. Changing the order means changing all three together.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameLayout {
+    /// What this frame is called in diagnostics.
+    pub name: &'static str,
+    pub subsystem: u8,
+    /// The part byte, `input[1]`, for subsystems that have parts.
+    pub part: Option<u8>,
+    /// The length of the whole frame, including the subsystem and part bytes.
+    pub length: u32,
+    /// The name of each payload byte, in order, starting right after the
+    /// header. These match the field names of the decoded type.
+    pub fields: &'static [&'static str],
+    /// Builds a message from a payload, in `fields` order.
+    decode: fn(&[u8]) -> Result<Message, ParsingError>,
+    /// Gets a message's payload, in `fields` order, or `None` if it isn't
+    /// this kind of message.
+    encode: fn(&Message) -> Option<Vec<u8>>,
+}
+
+impl FrameLayout {
+    /// How many bytes come before the payload: the subsystem byte, and the
+    /// part byte if there is one.
+    pub const fn header_len(&self) -> usize {
+        if self.part.is_some() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// The index of the checksum byte in the frame, for frames that have one.
+    pub fn checksum_offset(&self) -> Option<usize> {
+        self.checksum_field().map(|i| self.header_len() + i)
+    }
+
+    /// The index of the checksum byte in the payload.
+    fn checksum_field(&self) -> Option<usize> {
+        self.fields.iter().position(|&field| field == "checksum")
+    }
+
+    /// Checks that a payload's checksum, if this frame has one, matches the
+    /// rest of the payload.
+    fn check_checksum(&self, payload: &[u8]) -> Result<(), ParsingError> {
+        let Some(i) = self.checksum_field() else {
+            return Ok(());
+        };
+
+        let found = payload
+            .get(i)
+            .copied()
+            .ok_or(ParsingError::MalformedMessage)?;
+        let expected = sum_except(payload, i);
+
+        if found != expected {
+            return Err(ParsingError::BadChecksum {
+                subsystem: self.subsystem,
+                expected,
+                found,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Sums every payload byte except the one at `skip`, keeping the lowest 8
+/// bits. Every checksum is computed this way.
+fn sum_except(payload: &[u8], skip: usize) -> u8 {
+    payload
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != skip)
+        .fold(0u8, |sum, (_, &b)| sum.wrapping_add(b))
+}
+
+/// Every frame `parse` understands. To support a new subsystem (or part),
+/// add an entry here.
+///
+/// `parse` checks entries in order, so they're sorted by how much traffic
+/// they see: wheels commands are sent every control tick and make up most
+/// frames, followed by the arm during teleop. Keep the busiest frames near
+/// the top (`benches/parse.rs` measures this).
+const FRAMES: &[FrameLayout] = &[
+    FrameLayout {
+        name: "wheels",
+        subsystem: Wheels::SUBSYSTEM_BYTE,
+        part: Some(Wheels::PART_BYTE),
+        length: Wheels::FRAME_LENGTH,
+        fields: &[
+            "wheel0", "wheel1", "wheel2", "wheel3", "wheel4", "wheel5", "checksum",
+        ],
+        decode: |payload| {
+            let &[wheel0, wheel1, wheel2, wheel3, wheel4, wheel5, checksum] = payload else {
+                return Err(ParsingError::MalformedMessage);
+            };
+            Ok(Message::Wheels(Wheels::new(
+                wheel0, wheel1, wheel2, wheel3, wheel4, wheel5, checksum,
+            )))
+        },
+        encode: |message| match message {
+            Message::Wheels(w) => Some(vec![
+                w.wheel0, w.wheel1, w.wheel2, w.wheel3, w.wheel4, w.wheel5, w.checksum,
+            ]),
+            _ => None,
+        },
+    },
+    FrameLayout {
+        name: "arm",
+        subsystem: Arm::SUBSYSTEM_BYTE,
+        part: None,
+        length: Arm::FRAME_LENGTH,
+        fields: &[
+            "bicep",
+            "forearm",
+            "base",
+            "wrist_pitch",
+            "wrist_roll",
+            "claw",
+            "checksum",
+        ],
+        decode: |payload| {
+            let &[bicep, forearm, base, wrist_pitch, wrist_roll, claw, checksum] = payload else {
+                return Err(ParsingError::MalformedMessage);
+            };
+            Ok(Message::Arm(Arm {
+                bicep,
+                forearm,
+                base,
+                wrist_pitch,
+                wrist_roll,
+                claw,
+                checksum,
+            }))
+        },
+        encode: |message| match message {
+            Message::Arm(a) => Some(vec![
+                a.bicep,
+                a.forearm,
+                a.base,
+                a.wrist_pitch,
+                a.wrist_roll,
+                a.claw,
+                a.checksum,
+            ]),
+            _ => None,
+        },
+    },
+    FrameLayout {
+        name: "led",
+        subsystem: Led::SUBSYSTEM_BYTE,
+        part: Some(Led::PART_BYTE),
+        length: Led::FRAME_LENGTH,
+        fields: &["red", "green", "blue"],
+        decode: |payload| {
+            let &[red, green, blue] = payload else {
+                return Err(ParsingError::MalformedMessage);
+            };
+            Ok(Message::Led(Led { red, green, blue }))
+        },
+        encode: |message| match message {
+            Message::Led(l) => Some(vec![l.red, l.green, l.blue]),
+            _ => None,
+        },
+    },
+    FrameLayout {
+        name: "science",
+        subsystem: Science::SUBSYSTEM_BYTE,
+        part: None,
+        length: Science::FRAME_LENGTH,
+        fields: &[
+            "big_actuator",
+            "drill",
+            "small_actuator",
+            "test_tubes",
+            "camera_servo",
+            "checksum",
+        ],
+        decode: |payload| {
+            let &[big_actuator, drill, small_actuator, test_tubes, camera_servo, checksum] =
+                payload
+            else {
+                return Err(ParsingError::MalformedMessage);
+            };
+            Ok(Message::Science(Science {
+                big_actuator,
+                drill,
+                small_actuator,
+                test_tubes,
+                camera_servo,
+                checksum,
+            }))
+        },
+        encode: |message| match message {
+            Message::Science(s) => Some(vec![
+                s.big_actuator,
+                s.drill,
+                s.small_actuator,
+                s.test_tubes,
+                s.camera_servo,
+                s.checksum,
+            ]),
+            _ => None,
+        },
+    },
+    FrameLayout {
+        name: "science telemetry",
+        subsystem: ScienceTelemetry::SUBSYSTEM_BYTE,
+        part: None,
+        length: ScienceTelemetry::FRAME_LENGTH,
+        fields: &["big_actuator", "small_actuator", "checksum"],
+        decode: |payload| {
+            let &[big_actuator, small_actuator, checksum] = payload else {
+                return Err(ParsingError::MalformedMessage);
+            };
+            Ok(Message::ScienceTelemetry(ScienceTelemetry {
+                big_actuator,
+                small_actuator,
+                checksum,
+            }))
+        },
+        encode: |message| match message {
+            Message::ScienceTelemetry(t) => {
+                Some(vec![t.big_actuator, t.small_actuator, t.checksum])
+            }
+            _ => None,
+        },
+    },
+    FrameLayout {
+        name: "ack",
+        subsystem: Ack::SUBSYSTEM_BYTE,
+        part: None,
+        length: Ack::FRAME_LENGTH,
+        fields: &["sequence", "status"],
+        decode: |payload| {
+            let &[sequence, status] = payload else {
+                return Err(ParsingError::MalformedMessage);
+            };
+            Ok(Message::Ack(Ack { sequence, status }))
+        },
+        encode: |message| match message {
+            Message::Ack(a) => Some(vec![a.sequence, a.status]),
+            _ => None,
+        },
+    },
+];
+
+/// Looks up how long a frame for the given subsystem (and part, if the
+/// subsystem has parts) must be, including the subsystem and part bytes.
+///
+/// Returns `None` if there's no such frame. The part is ignored for
+/// subsystems without parts.
+///
+/// ```
+/// # use feedback::{parse::expected_len_for, Arm, Led, Science, ScienceTelemetry, Wheels};
+/// #
+/// assert_eq!(expected_len_for(Wheels::SUBSYSTEM_BYTE, Some(Wheels::PART_BYTE)), Some(9));
+/// assert_eq!(expected_len_for(Led::SUBSYSTEM_BYTE, Some(Led::PART_BYTE)), Some(5));
+/// assert_eq!(expected_len_for(Arm::SUBSYSTEM_BYTE, None), Some(8));
+/// assert_eq!(expected_len_for(Science::SUBSYSTEM_BYTE, None), Some(7));
+/// assert_eq!(expected_len_for(ScienceTelemetry::SUBSYSTEM_BYTE, None), Some(4));
+///
+/// assert_eq!(expected_len_for(Wheels::SUBSYSTEM_BYTE, None), None);
+/// assert_eq!(expected_len_for(0x09, None), None);
+/// ```
+pub const fn expected_len_for(subsystem: u8, part: Option<u8>) -> Option<u32> {
+    let mut i = 0;
+    while i < FRAMES.len() {
+        let spec = &FRAMES[i];
+
+        if spec.subsystem == subsystem {
+            match (spec.part, part) {
+                (None, _) => return Some(spec.length),
+                (Some(expected), Some(part)) if expected == part => return Some(spec.length),
+                _ => (),
+            }
         }
+
+        i += 1;
     }
+
+    None
+}
+
+/// Looks up the layout of the frame for the given subsystem (and part, if the
+/// subsystem has parts). The part is ignored for subsystems without parts.
+pub fn frame_layout(subsystem: u8, part: Option<u8>) -> Option<&'static FrameLayout> {
+    FRAMES
+        .iter()
+        .filter(|f| f.subsystem == subsystem)
+        .find(|f| f.part.is_none() || f.part == part)
+}
+
+/// The layout of every frame `parse` understands.
+///
+/// ```
+/// # use feedback::parse::frame_layouts;
+/// #
+/// let wheels = frame_layouts().iter().find(|l| l.name == "wheels").unwrap();
+/// assert_eq!(wheels.checksum_offset(), Some(8));
+/// ```
+pub fn frame_layouts() -> &'static [FrameLayout] {
+    FRAMES
+}
+
+/// Checks a frame's length and checksum straight from its bytes, using its
+/// layout, without decoding it.
+///
+/// ```
+/// # use feedback::{error::ParsingError, parse::{parse, verify_frame}};
+/// #
+/// assert_eq!(verify_frame(&[0x02, 1, 2, 3, 4, 5, 6, 21]), Ok(()));
+/// assert_eq!(
+///     verify_frame(&[0x02, 1, 2, 3, 4, 5, 6, 0]),
+///     Err(ParsingError::BadChecksum { subsystem: 0x02, expected: 21, found: 0 })
+/// );
+///
+/// // frames it can't check fail the same way they do in `parse`
+/// for frame in [&[][..], &[0x01], &[0x01, 0x99, 1, 2, 3], &[0x02, 1, 2], &[0x09]] {
+///     assert_eq!(verify_frame(frame), parse(frame).map(|_| ()), "{frame:?}");
+/// }
+/// ```
+pub fn verify_frame(frame: &[u8]) -> Result<(), ParsingError> {
+    let layout = checked_layout(frame)?;
+    layout.check_checksum(&frame[layout.header_len()..])
+}
+
+/// Whether any built-in frame uses the given subsystem byte.
+pub(crate) fn is_known_subsystem(subsystem: u8) -> bool {
+    FRAMES.iter().any(|f| f.subsystem == subsystem)
 }
 
 /// Parse an input slice into a valid message.
 /// ```
-/// # use feedback::parse::parse;
+/// # use feedback::{error::ParsingError, parse::{parse, Message, NO_PART}};
 /// #
-/// assert!(parse(&[0x09]).is_err());
+/// assert_eq!(parse(&[]), Err(ParsingError::ZeroLengthSlice));
+/// assert_eq!(parse(&[0x09]), Err(ParsingError::InvalidSubsystem(0x09)));
+///
+/// // wheels and LEDs need a part byte, and it has to be one they have
+/// assert_eq!(parse(&[0x01]), Err(ParsingError::NoEboxPart));
+/// assert_eq!(parse(&[0x01, 0x99, 1, 2, 3]), Err(ParsingError::InvalidSubsystem(0x99)));
+/// assert_eq!(
+///     parse(&[0x01, 0x02, 255, 0]),
+///     Err(ParsingError::LengthInconsistency { subsystem: 0x01, part: 0x02, length: 4, expected_length: 5 })
+/// );
+///
+/// // the arm and science package have no part, so errors report `NO_PART`
+/// assert_eq!(
+///     parse(&[0x02, 1, 2, 3]),
+///     Err(ParsingError::LengthInconsistency { subsystem: 0x02, part: NO_PART, length: 4, expected_length: 8 })
+/// );
+/// assert_eq!(
+///     parse(&[0x03, 1, 2, 3]),
+///     Err(ParsingError::LengthInconsistency { subsystem: 0x03, part: NO_PART, length: 4, expected_length: 7 })
+/// );
+///
+/// assert!(matches!(parse(&[0x01, 0x01, 126, 126, 126, 126, 126, 126, 244]), Ok(Message::Wheels(_))));
+/// assert!(matches!(parse(&[0x01, 0x02, 255, 0, 0]), Ok(Message::Led(_))));
+/// assert!(matches!(parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]), Ok(Message::Arm(_))));
+/// assert!(matches!(parse(&[0x03, 1, 2, 3, 4, 5, 15]), Ok(Message::Science(_))));
+/// assert!(matches!(parse(&[0x04, 40, 2, 42]), Ok(Message::ScienceTelemetry(_))));
+///
+/// let Ok(Message::Ack(ack)) = parse(&[0x05, 17, 0x00]) else { panic!("not an ack") };
+/// assert_eq!((ack.sequence, ack.is_ok()), (17, true));
 /// ```
 pub fn parse(input: &[u8]) -> Result<Message, ParsingError> {
-    let input_len = input.len() as u32;
+    let layout = checked_layout(input)?;
+    (layout.decode)(&input[layout.header_len()..])
+}
+
+/// Finds the layout of the frame in an input slice, and checks that the slice
+/// is the right length for it. `parse` and `verify_frame` both start here, so
+/// they reject bad frames with the same errors.
+fn checked_layout(input: &[u8]) -> Result<&'static FrameLayout, ParsingError> {
+    let layout = header_layout(input)?;
+
+    check_length(
+        input.len() as u32,
+        layout.subsystem,
+        layout.part.unwrap_or(NO_PART),
+        layout.length,
+    )?;
+    Ok(layout)
+}
 
+/// Finds the layout named by the frame's subsystem and part bytes, without
+/// checking its length.
+fn header_layout(input: &[u8]) -> Result<&'static FrameLayout, ParsingError> {
     // check if we have a subsystem byte
-    if input_len < 1 {
+    let Some(&subsystem) = input.first() else {
         return Err(ParsingError::ZeroLengthSlice);
-    }
+    };
 
-    // we do! let's match on it
-    let subsystem = input[0];
+    // we do! find the frames it could be
+    let mut candidates = FRAMES
+        .iter()
+        .filter(|f| f.subsystem == subsystem)
+        .peekable();
+
+    let layout = match candidates.peek() {
+        // otherwise, we got invalid input
+        None => return Err(ParsingError::InvalidSubsystem(subsystem)),
 
-    match subsystem {
-        Wheels::SUBSYSTEM_BYTE => {
-            // you have to specify the part byte
-            if input_len < 2 {
+        // this subsystem has parts, so you have to specify the part byte
+        Some(FrameLayout { part: Some(_), .. }) => {
+            let Some(&part) = input.get(1) else {
                 return Err(ParsingError::NoEboxPart);
-            }
+            };
 
-            // it exists! now we can parse it
-            // parse the second byte, input[1], to tell if it's wheel or leds
-            let part = input[1];
-            match part {
-                // wheel part
-                Wheels::PART_BYTE => {
-                    check_length(input_len, subsystem, part, 9)?;
-
-                    Ok(Message::Wheels(Wheels::new(
-                        input[2], input[3], input[4], input[5], input[6], input[7], input[8],
-                    )))
-                }
+            candidates
+                .find(|f| f.part == Some(part))
+                .ok_or(ParsingError::InvalidSubsystem(part))?
+        }
 
-                // leds part
-                Led::PART_BYTE => {
-                    check_length(input_len, subsystem, part, 5)?;
+        Some(layout) => *layout,
+    };
 
-                    Ok(Message::Led(Led {
-                        red: input[2],
-                        green: input[3],
-                        blue: input[4],
-                    }))
-                }
+    Ok(layout)
+}
+
+/// Like `parse`, but accepts frames with zeroes padded onto the end, as sent by
+/// firmware that always sends fixed-size datagrams.
+///
+/// Trailing zeroes past the frame's expected length are dropped before
+/// parsing. A frame can't tell padding apart from payload that happens to be
+/// zero, so a frame that lost bytes in transit and was then padded will parse
+/// with zeroes where those bytes should be. Use `parse` when frames are never
+/// padded.
+///
+/// ```
+/// # use feedback::parse::{parse, parse_padded, Message};
+/// #
+/// let mut datagram = vec![0x0; 32];
+/// datagram[..9].copy_from_slice(&[0x01, 0x01, 126, 126, 126, 126, 126, 126, 244]);
+///
+/// assert!(parse(&datagram).is_err());
+/// assert!(matches!(parse_padded(&datagram), Ok(Message::Wheels(w)) if w.checksum == 244));
+///
+/// // only zeroes count as padding
+/// datagram[20] = 0x01;
+/// assert!(parse_padded(&datagram).is_err());
+/// ```
+pub fn parse_padded(input: &[u8]) -> Result<Message, ParsingError> {
+    let Some(&subsystem) = input.first() else {
+        return Err(ParsingError::ZeroLengthSlice);
+    };
+
+    match expected_len_for(subsystem, input.get(1).copied()) {
+        Some(expected)
+            if input.len() > expected as usize
+                && input[expected as usize..].iter().all(|&b| b == 0x0) =>
+        {
+            parse(&input[..expected as usize])
+        }
+        _ => parse(input),
+    }
+}
+
+/// Like `parse`, but frames from subsystems this crate doesn't know about come
+/// back raw as `Message::Custom` instead of failing with `InvalidSubsystem`.
+/// This lets newer firmware's frames be logged and routed before this crate
+/// understands them.
+///
+/// Known subsystems are parsed as strictly as `parse` does.
+///
+/// ```
+/// # use feedback::{error::ParsingError, parse::{parse, parse_lenient, Message}};
+/// #
+/// let frame = [0x42, 1, 2, 3];
+/// assert_eq!(parse(&frame), Err(ParsingError::InvalidSubsystem(0x42)));
+///
+/// let Ok(Message::Custom(unknown)) = parse_lenient(&frame) else { panic!("not passed through") };
+/// assert_eq!((unknown.subsystem, unknown.bytes), (0x42, frame.to_vec()));
+///
+/// // a known subsystem with the wrong length is still rejected
+/// assert!(parse_lenient(&[0x02, 1, 2]).is_err());
+///
+/// // and so is a known subsystem with a part it doesn't have
+/// assert_eq!(parse_lenient(&[0x01, 0x99, 1, 2, 3]), Err(ParsingError::InvalidSubsystem(0x99)));
+/// ```
+pub fn parse_lenient(input: &[u8]) -> Result<Message, ParsingError> {
+    match input.first() {
+        Some(&subsystem) if !is_known_subsystem(subsystem) => CustomMessage::from_frame(input)
+            .map(Message::Custom)
+            .ok_or(ParsingError::ZeroLengthSlice),
+        _ => parse(input),
+    }
+}
+
+/// Something suspicious about a frame that still parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Warning {
+    /// The checksum byte doesn't match the payload. `Message::validate` would
+    /// reject this message.
+    BadChecksum { expected: u8, found: u8 },
+    /// A field is outside the range the subsystem accepts.
+    OutOfRange {
+        field: &'static str,
+        value: u8,
+        max: u8,
+    },
+    /// The wheels on one side are told to turn in opposite directions, which
+    /// drags the rover instead of driving it. `side` is `"left"` or `"right"`.
+    SplitSide { side: &'static str },
+}
 
-                malformed_part => {
-                    // invalid input
-                    Err(ParsingError::InvalidSubsystem(malformed_part))
+/// Like `parse`, but also returns anything suspicious about the frame that
+/// isn't bad enough to reject it.
+///
+/// ```
+/// # use feedback::parse::{parse_with_warnings, Warning};
+/// #
+/// let (_, warnings) = parse_with_warnings(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+/// assert!(warnings.is_empty());
+///
+/// // one left wheel in reverse, with the wrong checksum
+/// let (_, warnings) = parse_with_warnings(&[0x01, 0x01, 200, 100, 200, 126, 126, 126, 0]).unwrap();
+/// assert_eq!(
+///     warnings,
+///     [
+///         Warning::BadChecksum { expected: 110, found: 0 },
+///         Warning::SplitSide { side: "left" },
+///     ]
+/// );
+///
+/// // test tube 12 doesn't exist
+/// let (_, warnings) = parse_with_warnings(&[0x03, 0, 0, 0, 12, 0, 12]).unwrap();
+/// assert_eq!(warnings, [Warning::OutOfRange { field: "test_tubes", value: 12, max: 11 }]);
+/// ```
+pub fn parse_with_warnings(input: &[u8]) -> Result<(Message, Vec<Warning>), ParsingError> {
+    let message = parse(input)?;
+    let mut warnings = Vec::new();
+
+    if let Err(ParsingError::BadChecksum {
+        expected, found, ..
+    }) = verify_frame(input)
+    {
+        warnings.push(Warning::BadChecksum { expected, found });
+    }
+
+    match &message {
+        Message::Wheels(wheels) => {
+            let sides = [
+                ("left", [wheels.wheel0, wheels.wheel1, wheels.wheel2]),
+                ("right", [wheels.wheel3, wheels.wheel4, wheels.wheel5]),
+            ];
+
+            for (side, speeds) in sides {
+                let speeds = speeds.map(crate::signed_speed);
+                if speeds.iter().any(|&s| s > 0) && speeds.iter().any(|&s| s < 0) {
+                    warnings.push(Warning::SplitSide { side });
                 }
             }
         }
+        Message::Science(science) => {
+            if let Err(ParsingError::OutOfRange { field, value, max }) = science.validate() {
+                warnings.push(Warning::OutOfRange { field, value, max });
+            }
+        }
+        _ => (),
+    }
 
-        Arm::SUBSYSTEM_BYTE => {
-            check_length(input_len, subsystem, 0x00, 8)?;
+    Ok((message, warnings))
+}
 
-            let arm = Arm {
-                bicep: input[1],
-                forearm: input[2],
-                base: input[3],
-                wrist_pitch: input[4],
-                wrist_roll: input[5],
-                claw: input[6],
-                checksum: input[7],
-            };
+/// Walks through an input slice the way `parse` and `verify_frame` would,
+/// describing each step in plain words. If the slice isn't a valid message,
+/// the last line says where it went wrong, including a checksum mismatch.
+///
+/// This is meant for troubleshooting frames by hand. Use `parse` for
+/// anything else.
+///
+/// ```
+/// # use feedback::parse::explain;
+/// #
+/// assert_eq!(
+///     explain(&[0x01, 0x99]),
+///     "byte 0 = 0x01 (wheels or led)\nbyte 1 = 0x99, which is not a valid part"
+/// );
+/// assert_eq!(
+///     explain(&[0x02, 0x7e]),
+///     "byte 0 = 0x02 (arm)\nthe frame is 2 bytes long, but an arm frame is 8 bytes"
+/// );
+/// assert_eq!(
+///     explain(&[0x02, 1, 2, 3, 4, 5, 6, 99]).lines().last(),
+///     Some("byte 7 = 0x63, but the checksum should be 0x15")
+/// );
+/// assert_eq!(
+///     explain(&[0x02, 1, 2, 3, 4, 5, 6, 21]).lines().last(),
+///     Some("so it's a valid arm message")
+/// );
+/// ```
+#[pyfunction]
+pub fn explain(input: &[u8]) -> String {
+    let Some(&subsystem) = input.first() else {
+        return "the frame is empty, so there's no subsystem byte".into();
+    };
 
-            Ok(Message::Arm(arm))
+    let names: Vec<&str> = FRAMES
+        .iter()
+        .filter(|f| f.subsystem == subsystem)
+        .map(|f| f.name)
+        .collect();
+    if names.is_empty() {
+        return format!("byte 0 = {subsystem:#04x}, which is not a valid subsystem");
+    }
+
+    let mut steps = vec![format!(
+        "byte 0 = {subsystem:#04x} ({})",
+        names.join(" or ")
+    )];
+
+    // the subsystem is known, so the only header errors left are about the
+    // part byte
+    let layout = match header_layout(input) {
+        Ok(layout) => layout,
+        Err(ParsingError::NoEboxPart) => {
+            steps.push("byte 1 is missing, but this subsystem needs a part byte".into());
+            return steps.join("\n");
+        }
+        Err(ParsingError::InvalidSubsystem(part)) => {
+            steps.push(format!("byte 1 = {part:#04x}, which is not a valid part"));
+            return steps.join("\n");
         }
+        Err(e) => {
+            steps.push(e.to_string());
+            return steps.join("\n");
+        }
+    };
 
-        Science::SUBSYSTEM_BYTE => {
-            // the given slice was malformed. 😖
-            check_length(input_len, subsystem, 0x0, 7)?;
+    if let Some(part) = layout.part {
+        steps.push(format!("byte 1 = {part:#04x} ({})", layout.name));
+    }
 
-            let sci = Science {
-                big_actuator: input[1],
-                drill: input[2],
-                small_actuator: input[3],
-                test_tubes: input[4],
-                camera_servo: input[5],
-                checksum: input[6],
-            };
+    let article = if layout.name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
 
-            Ok(Message::Science(sci))
-        }
+    if let Err(ParsingError::LengthInconsistency { length, .. }) = checked_layout(input) {
+        steps.push(format!(
+            "the frame is {length} bytes long, but {article} {} frame is {} bytes",
+            layout.name, layout.length
+        ));
+        return steps.join("\n");
+    }
 
-        // otherwise, we got invalid input
-        malformed_subsys => Err(ParsingError::InvalidSubsystem(malformed_subsys)),
+    steps.push(format!(
+        "the frame is {} bytes long, as {article} {} frame should be",
+        input.len(),
+        layout.name
+    ));
+
+    if let Some(offset) = layout.checksum_offset() {
+        match layout.check_checksum(&input[layout.header_len()..]) {
+            Err(ParsingError::BadChecksum {
+                expected, found, ..
+            }) => {
+                steps.push(format!(
+                    "byte {offset} = {found:#04x}, but the checksum should be {expected:#04x}"
+                ));
+                return steps.join("\n");
+            }
+            _ => steps.push(format!(
+                "byte {offset} = {:#04x}, which matches the checksum",
+                input[offset]
+            )),
+        }
     }
+
+    steps.push(format!("so it's a valid {} message", layout.name));
+    steps.join("\n")
+}
+
+/// The total number of bytes the given messages take up when encoded back to
+/// back. Handy for checking a batch against the link's MTU before encoding it.
+///
+/// ```
+/// # use feedback::parse::{batch_len, parse};
+/// #
+/// let batch = [
+///     parse(&[0x01, 0x01, 126, 126, 126, 126, 126, 126, 244]).unwrap(),
+///     parse(&[0x01, 0x02, 255, 0, 0]).unwrap(),
+///     parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap(),
+/// ];
+///
+/// let encoded: Vec<u8> = batch.iter().flat_map(|m| m.encode()).collect();
+/// assert_eq!(batch_len(&batch), encoded.len());
+/// assert_eq!(batch_len(&batch), 22);
+/// ```
+pub fn batch_len(messages: &[Message]) -> usize {
+    messages.iter().map(Message::encoded_len).sum()
+}
+
+/// A named, canonical frame for every kind of message, with the message it
+/// must parse to. Bindings for other languages can check their own encoders
+/// and parsers against these.
+///
+/// The science telemetry and ack frames are provisional until their layouts
+/// are confirmed against the firmware (see `ScienceTelemetry` and `Ack`), so
+/// those two vectors may still change.
+///
+/// ```
+/// # use feedback::parse::{parse, reference_vectors};
+/// #
+/// for (name, frame, message) in reference_vectors() {
+///     assert_eq!(parse(&frame).as_ref(), Ok(&message), "{name}");
+///     assert_eq!(message.encode(), frame, "{name}");
+///     assert_eq!(message.validate(), Ok(()), "{name}");
+/// }
+/// ```
+pub fn reference_vectors() -> Vec<(&'static str, Vec<u8>, Message)> {
+    vec![
+        (
+            "wheels_neutral",
+            vec![0x01, 0x01, 126, 126, 126, 126, 126, 126, 244],
+            Message::Wheels(Wheels::new(126, 126, 126, 126, 126, 126, 244)),
+        ),
+        (
+            "led_red",
+            vec![0x01, 0x02, 255, 0, 0],
+            Message::Led(Led {
+                red: 255,
+                green: 0,
+                blue: 0,
+            }),
+        ),
+        (
+            "arm_pose",
+            vec![0x02, 10, 20, 30, 40, 50, 60, 210],
+            Message::Arm(Arm {
+                bicep: 10,
+                forearm: 20,
+                base: 30,
+                wrist_pitch: 40,
+                wrist_roll: 50,
+                claw: 60,
+                checksum: 210,
+            }),
+        ),
+        (
+            "science_tube_3",
+            vec![0x03, 126, 0, 126, 3, 90, 89],
+            Message::Science(Science {
+                big_actuator: 126,
+                drill: 0,
+                small_actuator: 126,
+                test_tubes: 3,
+                camera_servo: 90,
+                checksum: 89,
+            }),
+        ),
+        (
+            "science_telemetry",
+            vec![0x04, 200, 100, 44],
+            Message::ScienceTelemetry(ScienceTelemetry {
+                big_actuator: 200,
+                small_actuator: 100,
+                checksum: 44,
+            }),
+        ),
+        (
+            "ack_ok",
+            vec![0x05, 17, 0x00],
+            Message::Ack(Ack {
+                sequence: 17,
+                status: Ack::STATUS_OK,
+            }),
+        ),
+    ]
+}
+
+/// A named, canonical frame for every kind of message, with the message it
+/// must parse to. The science telemetry and ack frames are provisional, like
+/// in `reference_vectors`.
+#[pyfunction(name = "reference_vectors")]
+pub fn pyreference_vectors(py: Python<'_>) -> Vec<(&'static str, Bound<'_, PyBytes>, PyMessage)> {
+    reference_vectors()
+        .into_iter()
+        .map(|(name, frame, message)| (name, PyBytes::new_bound(py, &frame), message.into()))
+        .collect()
+}
+
+/// Encode a message into the bytes that go on the wire.
+#[pyfunction(name = "encode")]
+pub fn pyencode(py: Python<'_>, message: PyMessage) -> Bound<'_, PyBytes> {
+    PyBytes::new_bound(py, &Message::from(message).encode())
 }
 
 /// Parse an input slice into a valid message.
 #[pyfunction(name = "parse")]
 pub fn pyparse(input: &[u8]) -> PyResult<PyMessage> {
-    parse(input)
-        .map_err(|e| PyValueError::new_err(e.to_string()))
-        .map(|t| t.into())
+    Ok(parse(input)?.into())
 }
 
 /// Checks if the given input length is equal to the expected length. If so, returns `Ok(())`.
@@ -179,3 +1338,46 @@ const fn check_length(
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// If a frame's `length` were ever shorter than its decoder expects, the
+    /// decoder has to fail instead of indexing past the end.
+    #[test]
+    fn short_payloads_dont_panic_decoders() {
+        for frame in FRAMES {
+            let payload = vec![0x0; frame.length as usize - frame.header_len() - 1];
+
+            assert_eq!(
+                (frame.decode)(&payload),
+                Err(ParsingError::MalformedMessage),
+                "{}",
+                frame.name
+            );
+        }
+    }
+
+    /// Each name in `fields` has to be the struct field its decoder reads that
+    /// byte into, or the checksum check and schema would point at the wrong
+    /// byte.
+    #[test]
+    fn fields_match_decoders() {
+        for frame in FRAMES {
+            // distinct bytes, small enough to be in range for every field
+            let payload: Vec<u8> = (1..=frame.fields.len() as u8).collect();
+            let message = (frame.decode)(&payload).unwrap();
+            let debug = format!("{message:?}");
+
+            for (&name, value) in frame.fields.iter().zip(&payload) {
+                assert!(
+                    debug.contains(&format!("{name}: {value},"))
+                        || debug.contains(&format!("{name}: {value} }}")),
+                    "{}: expected `{name}: {value}` in {debug}",
+                    frame.name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! # Registry
+//!
+//! A parser that downstream crates can teach new subsystems, without forking
+//! `parse`.
+
+use std::collections::HashMap;
+
+use crate::{
+    error::ParsingError,
+    parse::{self, frame_layouts, Message},
+};
+
+/// Turns a frame for one subsystem into a `Message`. The frame includes the
+/// subsystem byte.
+pub type Decoder = Box<dyn Fn(&[u8]) -> Result<Message, ParsingError> + Send + Sync>;
+
+/// Maps subsystem bytes to the decoders that parse their frames.
+///
+/// The built-in subsystems are registered from the start, so a fresh registry
+/// parses exactly what `parse::parse` does.
+///
+/// ```
+/// # use feedback::{error::ParsingError, parse::Message, registry::ParserRegistry, CustomMessage};
+/// #
+/// let mut registry = ParserRegistry::new();
+/// assert_eq!(registry.parse(&[0x42, 1, 2]).unwrap_err(), ParsingError::InvalidSubsystem(0x42));
+///
+/// registry.register(0x42, |frame| {
+///     CustomMessage::from_frame(frame)
+///         .map(Message::Custom)
+///         .ok_or(ParsingError::ZeroLengthSlice)
+/// });
+///
+/// let Ok(Message::Custom(custom)) = registry.parse(&[0x42, 1, 2]) else { panic!("not custom") };
+/// assert_eq!(custom.bytes, [0x42, 1, 2]);
+///
+/// // built-in subsystems still work
+/// assert!(matches!(registry.parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]), Ok(Message::Arm(_))));
+/// ```
+pub struct ParserRegistry {
+    decoders: HashMap<u8, Decoder>,
+}
+
+impl ParserRegistry {
+    /// Creates a registry with every built-in subsystem registered.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+
+        // subsystems with parts have several layouts, but one decoder each
+        for layout in frame_layouts() {
+            registry.register(layout.subsystem, parse::parse);
+        }
+
+        registry
+    }
+
+    /// Creates a registry with nothing registered, not even the built-in
+    /// subsystems.
+    pub fn empty() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers a decoder for frames starting with the given subsystem byte,
+    /// replacing any decoder (built-in or not) that was already there.
+    pub fn register<F>(&mut self, subsystem: u8, decoder: F)
+    where
+        F: Fn(&[u8]) -> Result<Message, ParsingError> + Send + Sync + 'static,
+    {
+        self.decoders.insert(subsystem, Box::new(decoder));
+    }
+
+    /// Parse an input slice into a valid message, using the decoder
+    /// registered for its subsystem byte.
+    pub fn parse(&self, input: &[u8]) -> Result<Message, ParsingError> {
+        let Some(subsystem) = input.first() else {
+            return Err(ParsingError::ZeroLengthSlice);
+        };
+
+        let decoder = self
+            .decoders
+            .get(subsystem)
+            .ok_or(ParsingError::InvalidSubsystem(*subsystem))?;
+
+        decoder(input)
+    }
+}
+
+impl Default for ParserRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,83 @@
+//! # ROS
+//!
+//! Stable ROS topic names for each kind of message, for bridging this
+//! protocol onto ROS topics.
+
+use std::collections::HashMap;
+
+use crate::parse::Message;
+
+impl Message {
+    /// The ROS topic this kind of message is published on.
+    ///
+    /// Custom messages all share `/rover/custom`. Use a `TopicMap` to give
+    /// them their own topics.
+    ///
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use feedback::parse::{parse, reference_vectors};
+    /// #
+    /// assert_eq!(parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap().topic_name(), "/rover/arm");
+    ///
+    /// // every kind of message gets its own topic
+    /// let vectors = reference_vectors();
+    /// let topics: HashSet<&str> = vectors.iter().map(|(_, _, m)| m.topic_name()).collect();
+    /// assert_eq!(topics.len(), vectors.len());
+    /// assert!(topics.iter().all(|t| t.starts_with("/rover/")));
+    /// ```
+    pub fn topic_name(&self) -> &'static str {
+        match self {
+            Message::Wheels(_) => "/rover/wheels",
+            Message::Led(_) => "/rover/led",
+            Message::Arm(_) => "/rover/arm",
+            Message::Science(_) => "/rover/science",
+            Message::ScienceTelemetry(_) => "/rover/science/telemetry",
+            Message::Ack(_) => "/rover/ack",
+            Message::Custom(_) => "/rover/custom",
+        }
+    }
+}
+
+/// Topic names for custom subsystems, falling back to `Message::topic_name`
+/// for everything else.
+///
+/// ```
+/// # use feedback::{parse::{parse, Message}, ros::TopicMap, CustomMessage};
+/// #
+/// let mut topics = TopicMap::new();
+/// topics.insert(0x42, "/rover/spectrometer");
+///
+/// let custom = Message::Custom(CustomMessage::from_frame(&[0x42, 7]).unwrap());
+/// assert_eq!(topics.topic_name(&custom), "/rover/spectrometer");
+///
+/// let arm = parse(&[0x02, 1, 2, 3, 4, 5, 6, 21]).unwrap();
+/// assert_eq!(topics.topic_name(&arm), "/rover/arm");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TopicMap {
+    custom: HashMap<u8, String>,
+}
+
+impl TopicMap {
+    /// Creates a map with no custom topics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publishes custom messages from the given subsystem on `topic`.
+    pub fn insert(&mut self, subsystem: u8, topic: impl Into<String>) {
+        self.custom.insert(subsystem, topic.into());
+    }
+
+    /// The topic the given message is published on.
+    pub fn topic_name<'a>(&'a self, message: &Message) -> &'a str {
+        match message {
+            Message::Custom(custom) => self
+                .custom
+                .get(&custom.subsystem)
+                .map(String::as_str)
+                .unwrap_or(message.topic_name()),
+            _ => message.topic_name(),
+        }
+    }
+}
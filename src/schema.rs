@@ -0,0 +1,97 @@
+//! # Schema
+//!
+//! A machine-readable description of every frame, for tooling and bindings in
+//! other languages. It's built from the same frame table `parse` uses, so it
+//! can't drift from what this crate actually accepts.
+
+use crate::parse::{frame_layouts, FrameLayout};
+
+/// Everything needed to encode or decode one kind of frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SubsystemSchema {
+    /// What the frame is called, like `"wheels"`.
+    pub name: &'static str,
+    /// The first byte of the frame.
+    pub subsystem: u8,
+    /// The second byte of the frame, for subsystems that have parts.
+    pub part: Option<u8>,
+    /// The length of the whole frame, including the subsystem and part bytes.
+    pub length: u32,
+    /// Every byte after the header, in order.
+    pub fields: Vec<FieldSchema>,
+}
+
+/// One field of a frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldSchema {
+    pub name: &'static str,
+    /// The field's Rust type. Every field is currently a `u8`.
+    pub ty: &'static str,
+    /// The field's index in the frame, counting the subsystem byte as 0.
+    pub offset: usize,
+    /// Whether this field is the checksum, the sum of the frame's other
+    /// fields.
+    pub is_checksum: bool,
+}
+
+impl From<&FrameLayout> for SubsystemSchema {
+    fn from(layout: &FrameLayout) -> Self {
+        let fields = layout
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, &name)| {
+                let offset = layout.header_len() + i;
+                FieldSchema {
+                    name,
+                    ty: "u8",
+                    offset,
+                    is_checksum: layout.checksum_offset() == Some(offset),
+                }
+            })
+            .collect();
+
+        Self {
+            name: layout.name,
+            subsystem: layout.subsystem,
+            part: layout.part,
+            length: layout.length,
+            fields,
+        }
+    }
+}
+
+/// Describes every frame this crate can parse.
+///
+/// ```
+/// # use feedback::{schema::protocol_schema, Ack, Arm, Led, Science, ScienceTelemetry, Wheels};
+/// #
+/// let schema = protocol_schema();
+///
+/// let mut subsystems: Vec<u8> = schema.iter().map(|s| s.subsystem).collect();
+/// subsystems.sort();
+/// subsystems.dedup();
+/// assert_eq!(
+///     subsystems,
+///     [
+///         Wheels::SUBSYSTEM_BYTE,
+///         Arm::SUBSYSTEM_BYTE,
+///         Science::SUBSYSTEM_BYTE,
+///         ScienceTelemetry::SUBSYSTEM_BYTE,
+///         Ack::SUBSYSTEM_BYTE,
+///     ]
+/// );
+///
+/// let led = schema.iter().find(|s| s.name == "led").unwrap();
+/// assert_eq!((led.subsystem, led.part), (Led::SUBSYSTEM_BYTE, Some(Led::PART_BYTE)));
+///
+/// let arm = schema.iter().find(|s| s.name == "arm").unwrap();
+/// assert_eq!(arm.fields[0].name, "bicep");
+/// assert_eq!(arm.fields[0].offset, 1);
+/// assert!(arm.fields.last().unwrap().is_checksum);
+/// ```
+pub fn protocol_schema() -> Vec<SubsystemSchema> {
+    frame_layouts().iter().map(SubsystemSchema::from).collect()
+}
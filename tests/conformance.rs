@@ -0,0 +1,102 @@
+//! Checks every frame `parse` knows about against the wire format.
+//!
+//! Frames are found by asking `expected_len_for` about every subsystem and
+//! part byte, so new subsystems are covered as soon as they're parseable.
+
+use feedback::parse::{expected_len_for, frame_layouts, parse, verify_frame};
+
+/// The header (subsystem byte, plus the part byte if there is one) and total
+/// length of every known frame.
+fn known_frames() -> Vec<(Vec<u8>, usize)> {
+    let mut frames = Vec::new();
+
+    for subsystem in 0..=u8::MAX {
+        if let Some(length) = expected_len_for(subsystem, None) {
+            frames.push((vec![subsystem], length as usize));
+            continue;
+        }
+
+        for part in 0..=u8::MAX {
+            if let Some(length) = expected_len_for(subsystem, Some(part)) {
+                frames.push((vec![subsystem, part], length as usize));
+            }
+        }
+    }
+
+    frames
+}
+
+#[test]
+fn every_frame_conforms() {
+    let frames = known_frames();
+    assert!(!frames.is_empty());
+
+    for (header, length) in frames {
+        // distinct payload bytes catch fields that are read or written out of order
+        let mut frame = header.clone();
+        frame.extend((header.len()..length).map(|i| i as u8));
+
+        let message = parse(&frame).unwrap_or_else(|e| panic!("{frame:?} didn't parse: {e}"));
+        assert_eq!(message.subsystem_byte(), header[0], "{frame:?}");
+        assert_eq!(message.encoded_len(), length, "{frame:?}");
+        assert_eq!(message.encode(), frame, "{frame:?}");
+
+        // an all-zero payload always sums to a zero checksum
+        let mut zeroed = header.clone();
+        zeroed.resize(length, 0x0);
+        assert_eq!(parse(&zeroed).unwrap().validate(), Ok(()), "{zeroed:?}");
+
+        // and any other length is rejected
+        assert!(parse(&frame[..length - 1]).is_err(), "{frame:?}");
+        frame.push(0x0);
+        assert!(parse(&frame).is_err(), "{frame:?}");
+    }
+}
+
+#[test]
+fn checksums_are_where_the_layout_says() {
+    for layout in frame_layouts() {
+        let mut frame: Vec<u8> = (0..layout.length as u8).collect();
+        frame[0] = layout.subsystem;
+        if let Some(part) = layout.part {
+            frame[1] = part;
+        }
+
+        // every payload byte is named
+        assert_eq!(
+            layout.header_len() + layout.fields.len(),
+            layout.length as usize,
+            "{}",
+            layout.name
+        );
+
+        let Some(offset) = layout.checksum_offset() else {
+            assert_eq!(verify_frame(&frame), Ok(()), "{}", layout.name);
+            continue;
+        };
+
+        // fill in the checksum by hand, from the layout alone
+        frame[offset] = frame
+            .iter()
+            .enumerate()
+            .skip(layout.header_len())
+            .filter(|&(i, _)| i != offset)
+            .fold(0u8, |sum, (_, &b)| sum.wrapping_add(b));
+
+        assert_eq!(verify_frame(&frame), Ok(()), "{}", layout.name);
+        let message = parse(&frame).unwrap();
+        assert_eq!(message.validate(), Ok(()), "{}", layout.name);
+        assert_eq!(message.encode(), frame, "{}", layout.name);
+
+        // and both checks notice when it's wrong
+        let good = frame.clone();
+        frame[offset] = frame[offset].wrapping_add(1);
+        assert!(verify_frame(&frame).is_err(), "{}", layout.name);
+        let mut message = parse(&frame).unwrap();
+        assert!(message.validate().is_err(), "{}", layout.name);
+
+        // until the checksum is recomputed in the same place
+        message.recompute_checksum();
+        assert_eq!(message.encode(), good, "{}", layout.name);
+    }
+}